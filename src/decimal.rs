@@ -0,0 +1,306 @@
+//! Nombres décimaux exacts (kuɗi) pour les calculs d'argent
+//!
+//! Un `Decimal` est un entier `units` accompagné d'une échelle `scale` :
+//! `12.50` est stocké comme `units = 1250`, `scale = 2`. L'addition, la
+//! soustraction et la multiplication sont exactes ; la division demande un
+//! nombre de décimales et un mode d'arrondi.
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::error::Error;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// Nombre de décimales par défaut pour une division avec `/`
+pub const DEFAULT_DIVISION_SCALE: u32 = 2;
+
+/// Nombre maximal de décimales conservées
+pub const MAX_SCALE: u32 = 28;
+
+/// Mode d'arrondi utilisé pour la division et l'arrondi
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// « rabi » : au plus proche, la moitié s'éloigne de zéro (0.125 → 0.13)
+    HalfUp,
+    /// « banki » : au plus proche, la moitié va au chiffre pair (0.125 → 0.12)
+    HalfEven,
+    /// « ƙasa » : vers zéro, on coupe simplement
+    Down,
+    /// « sama » : en s'éloignant de zéro dès qu'il y a un reste
+    Up,
+}
+
+impl RoundingMode {
+    /// Retrouve un mode d'arrondi à partir de son nom haoussa
+    pub fn from_name(name: &str) -> Result<RoundingMode, Error> {
+        match name {
+            "rabi" => Ok(RoundingMode::HalfUp),
+            "banki" => Ok(RoundingMode::HalfEven),
+            "ƙasa" | "kasa" => Ok(RoundingMode::Down),
+            "sama" => Ok(RoundingMode::Up),
+            other => Err(Error::runtime_error(&format!(
+                "Ba a san yanayin zagaye '{}' ba: yi amfani da \"rabi\", \"banki\", \"ƙasa\" ko \"sama\" (unknown rounding mode)",
+                other
+            ))),
+        }
+    }
+}
+
+/// Nombre décimal exact à virgule fixe
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Decimal {
+    units: i128,
+    scale: u32,
+}
+
+impl Decimal {
+    /// Crée un décimal à partir de ses unités et de son échelle
+    pub fn new(units: i128, scale: u32) -> Self {
+        Decimal { units, scale }
+    }
+
+    /// Convertit un entier sans décimales
+    pub fn from_int(n: i64) -> Self {
+        Decimal::new(n as i128, 0)
+    }
+
+    /// Lit un décimal écrit comme "1,250.50", "-0.10" ou "1_000"
+    pub fn parse(text: &str) -> Result<Decimal, Error> {
+        let invalid = || Error::runtime_error(&format!(
+            "'{}' ba kuɗi ba ne (invalid decimal)",
+            text
+        ));
+
+        let trimmed = text.trim();
+        let (negative, digits) = match trimmed.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+        };
+
+        let mut units: i128 = 0;
+        let mut scale: u32 = 0;
+        let mut seen_point = false;
+        let mut seen_digit = false;
+
+        for ch in digits.chars() {
+            match ch {
+                '0'..='9' => {
+                    seen_digit = true;
+                    units = units.checked_mul(10)
+                        .and_then(|u| u.checked_add(ch as i128 - '0' as i128))
+                        .ok_or_else(|| Error::integer_overflow("kudi"))?;
+                    if seen_point {
+                        scale += 1;
+                    }
+                }
+                '.' if !seen_point => seen_point = true,
+                ',' | '_' if !seen_point => {}
+                _ => return Err(invalid()),
+            }
+        }
+
+        if !seen_digit || scale > MAX_SCALE {
+            return Err(invalid());
+        }
+
+        Ok(Decimal::new(if negative { -units } else { units }, scale))
+    }
+
+    /// Convertit un flottant en passant par son écriture la plus courte (0.1 → 0.1)
+    pub fn from_f64(f: f64) -> Result<Decimal, Error> {
+        if !f.is_finite() {
+            return Err(Error::runtime_error("Ba za a iya canza wannan lambar zuwa kuɗi ba (cannot convert to decimal)"));
+        }
+        Decimal::parse(&f.to_string())
+    }
+
+    /// Convertit en flottant (peut perdre de la précision)
+    pub fn to_f64(&self) -> f64 {
+        self.units as f64 / 10f64.powi(self.scale as i32)
+    }
+
+    /// Partie entière (arrondie vers zéro), si elle tient dans un i64
+    pub fn trunc_to_i64(&self) -> Option<i64> {
+        let whole = self.units / 10i128.checked_pow(self.scale)?;
+        i64::try_from(whole).ok()
+    }
+
+    /// Nombre de chiffres après la virgule
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Vérifie si la valeur est nulle
+    pub fn is_zero(&self) -> bool {
+        self.units == 0
+    }
+
+    /// Même valeur exprimée avec une échelle plus grande
+    fn rescale(&self, scale: u32) -> Option<Decimal> {
+        let factor = 10i128.checked_pow(scale.checked_sub(self.scale)?)?;
+        Some(Decimal::new(self.units.checked_mul(factor)?, scale))
+    }
+
+    /// Ramène deux décimaux à la même échelle
+    fn align(&self, other: &Decimal) -> Option<(i128, i128, u32)> {
+        let scale = self.scale.max(other.scale);
+        Some((self.rescale(scale)?.units, other.rescale(scale)?.units, scale))
+    }
+
+    /// Addition exacte (None en cas de dépassement)
+    pub fn checked_add(&self, other: &Decimal) -> Option<Decimal> {
+        let (a, b, scale) = self.align(other)?;
+        Some(Decimal::new(a.checked_add(b)?, scale))
+    }
+
+    /// Soustraction exacte (None en cas de dépassement)
+    pub fn checked_sub(&self, other: &Decimal) -> Option<Decimal> {
+        let (a, b, scale) = self.align(other)?;
+        Some(Decimal::new(a.checked_sub(b)?, scale))
+    }
+
+    /// Multiplication exacte : 0.10 * 3 = 0.30
+    ///
+    /// Les échelles s'additionnent; au-delà de MAX_SCALE décimales le produit
+    /// n'est plus représentable et l'erreur le dit, plutôt qu'un dépassement.
+    pub fn checked_mul(&self, other: &Decimal) -> Result<Decimal, Error> {
+        let scale = self.scale + other.scale;
+        if scale > MAX_SCALE {
+            return Err(Error::too_many_decimal_places(MAX_SCALE));
+        }
+        self.units.checked_mul(other.units)
+            .map(|units| Decimal::new(units, scale))
+            .ok_or_else(|| Error::integer_overflow("*"))
+    }
+
+    /// Opposé
+    pub fn checked_neg(&self) -> Option<Decimal> {
+        Some(Decimal::new(self.units.checked_neg()?, self.scale))
+    }
+
+    /// Division arrondie à `scale` décimales selon `mode`
+    pub fn checked_div(&self, other: &Decimal, scale: u32, mode: RoundingMode) -> Result<Decimal, Error> {
+        if other.is_zero() {
+            return Err(Error::division_by_zero());
+        }
+        if scale > MAX_SCALE {
+            return Err(Error::too_many_decimal_places(MAX_SCALE));
+        }
+
+        // (a / 10^sa) / (b / 10^sb) * 10^scale = a * 10^(scale + sb) / (b * 10^sa)
+        let overflow = || Error::integer_overflow("/");
+        let numerator = 10i128.checked_pow(scale + other.scale)
+            .and_then(|f| self.units.checked_mul(f))
+            .ok_or_else(overflow)?;
+        let denominator = 10i128.checked_pow(self.scale)
+            .and_then(|f| other.units.checked_mul(f))
+            .ok_or_else(overflow)?;
+
+        Ok(Decimal::new(divide_rounded(numerator, denominator, mode), scale))
+    }
+
+    /// Arrondit à `scale` décimales selon `mode`
+    pub fn round(&self, scale: u32, mode: RoundingMode) -> Result<Decimal, Error> {
+        if scale >= self.scale {
+            return self.rescale(scale).ok_or_else(|| Error::integer_overflow("zagaye"));
+        }
+        let factor = 10i128.pow(self.scale - scale);
+        Ok(Decimal::new(divide_rounded(self.units, factor, mode), scale))
+    }
+
+    /// Écriture simple sans séparateurs de milliers: -1234.50
+    pub fn to_plain_string(&self) -> String {
+        let (whole, fraction) = self.split_digits();
+        let sign = if self.units < 0 { "-" } else { "" };
+        match fraction {
+            Some(fraction) => format!("{}{}.{}", sign, whole, fraction),
+            None => format!("{}{}", sign, whole),
+        }
+    }
+
+    /// Chiffres de la partie entière et de la partie décimale (sans signe)
+    fn split_digits(&self) -> (String, Option<String>) {
+        let digits = self.units.unsigned_abs().to_string();
+        let scale = self.scale as usize;
+        if scale == 0 {
+            return (digits, None);
+        }
+
+        let padded = format!("{:0>width$}", digits, width = scale + 1);
+        let (whole, fraction) = padded.split_at(padded.len() - scale);
+        (whole.to_string(), Some(fraction.to_string()))
+    }
+}
+
+/// Division entière avec arrondi selon `mode`
+fn divide_rounded(numerator: i128, denominator: i128, mode: RoundingMode) -> i128 {
+    let quotient = numerator / denominator;
+    let remainder = (numerator % denominator).unsigned_abs();
+    if remainder == 0 {
+        return quotient;
+    }
+
+    let step = if (numerator < 0) != (denominator < 0) { -1 } else { 1 };
+    let divisor = denominator.unsigned_abs();
+    // Comparer 2 * reste à diviseur sans risque de dépassement
+    let half = remainder.cmp(&(divisor - remainder));
+
+    let away_from_zero = match mode {
+        RoundingMode::Down => false,
+        RoundingMode::Up => true,
+        RoundingMode::HalfUp => half != Ordering::Less,
+        RoundingMode::HalfEven => half == Ordering::Greater || (half == Ordering::Equal && quotient % 2 != 0),
+    };
+
+    if away_from_zero { quotient + step } else { quotient }
+}
+
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    /// Compare les valeurs : 1.50 et 1.5 sont égaux
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.align(other) {
+            Some((a, b, _)) => a.cmp(&b),
+            None => self.to_f64().partial_cmp(&other.to_f64()).unwrap_or(Ordering::Equal),
+        }
+    }
+}
+
+impl fmt::Display for Decimal {
+    /// Affiche avec des séparateurs de milliers: 1,250,000.50
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (whole, fraction) = self.split_digits();
+
+        let mut grouped = String::new();
+        for (i, ch) in whole.chars().enumerate() {
+            if i > 0 && (whole.len() - i) % 3 == 0 {
+                grouped.push(',');
+            }
+            grouped.push(ch);
+        }
+
+        if self.units < 0 {
+            write!(f, "-")?;
+        }
+        match fraction {
+            Some(fraction) => write!(f, "{}.{}", grouped, fraction),
+            None => write!(f, "{}", grouped),
+        }
+    }
+}
@@ -5,51 +5,384 @@
 
 use std::fmt;
 
+use crate::lexer::Span;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// Appel de fonction en cours lorsqu'une erreur s'est produite
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Frame {
+    /// Nom de la fonction appelée (« aiki » pour une fonction anonyme)
+    pub function: String,
+    /// Position de l'appel dans le code source (absente pour un rappel depuis map, filter, ...)
+    pub call_site: Option<Span>,
+}
+
+/// Nature précise d'une erreur, qui choisit son conseil (`hint`)
+///
+/// Le code est fixé par le constructeur : reformuler un message ne fait pas
+/// disparaître le conseil qui l'accompagne.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum ErrorCode {
+    /// Erreur sans conseil particulier
+    Generic,
+    /// Jimla sans guillemet fermant
+    UnterminatedString,
+    /// Caractère ou mot inconnu du lexer
+    UnknownToken,
+    /// Lambar entière trop grande pour i64 dans le code source
+    IntegerLiteralTooLarge,
+    /// Programme qui ne commence pas par `fara`
+    MissingBegin,
+    /// Fin du fichier au milieu d'une construction
+    UnexpectedEof,
+    /// Mot réservé utilisé comme nom de variable, de fonction ou de paramètre
+    ReservedWord,
+    /// Code imbriqué sur trop de niveaux
+    NestingTooDeep,
+    /// Variable lue avant d'être déclarée
+    VariableNotFound,
+    /// Appel d'une fonction inconnue
+    FunctionNotFound,
+    /// Division ou reste par zéro
+    DivisionByZero,
+    /// Kuɗi combiné avec une lambar mai daɗewa
+    DecimalFloatMix,
+    /// Kuɗi avec plus de décimales que `decimal::MAX_SCALE`
+    TooManyDecimalPlaces,
+    /// Dépassement de capacité des entiers
+    IntegerOverflow,
+    /// Index hors d'une liste, d'une jimla ou d'un zango
+    IndexOutOfBounds,
+    /// Trop d'appels imbriqués
+    RecursionTooDeep,
+    /// `karɓa` après la fin de l'entrée
+    EndOfInput,
+    /// `karɓa` après la dernière ligne préparée (`--input`)
+    ScriptedInputExhausted,
+    /// `tabbatar` en échec, sans message ni comparaison à montrer
+    UnexplainedAssertion,
+}
+
 /// Types d'erreurs dans Dabara
+///
+/// Les erreurs de tokenisation, de syntaxe et d'exécution peuvent porter
+/// la position (ligne, colonne) du code source où elles se sont produites.
+/// Les erreurs d'exécution gardent aussi la pile des appels (du plus récent
+/// au plus ancien) au moment où elles sont survenues.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum Error {
     /// Erreur de tokenisation (lexer)
-    LexError(String),
+    LexError(String, Option<Span>, ErrorCode),
     /// Erreur d'analyse syntaxique (parser)
-    ParseError(String),
+    ParseError(String, Option<Span>, ErrorCode),
     /// Erreur d'exécution (runtime)
-    RuntimeError(String),
+    RuntimeError(String, Option<Span>, Vec<Frame>, ErrorCode),
+    /// Erreur levée par le programme avec `jefa`
+    Thrown(String, Option<Span>, Vec<Frame>),
+    /// Budget de pas ou délai d'exécution épuisé (exécution encadrée)
+    LimitExceeded(String, Option<Span>, Vec<Frame>),
     /// Erreur de fichier
     FileError(String),
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            Error::LexError(msg) => write!(f, "Kuskure na Tokenization: {}", msg),
-            Error::ParseError(msg) => write!(f, "Kuskure na Syntax: {}", msg),
-            Error::RuntimeError(msg) => write!(f, "Kuskure na Runtime: {}", msg),
-            Error::FileError(msg) => write!(f, "Kuskure na Fayil: {}", msg),
+        write!(f, "{}", self.headline())?;
+
+        if let Some(span) = self.span() {
+            write!(f, " (layi {}, ginshiƙi {})", span.line, span.column)?;
         }
+
+        write!(f, ": {}", self.message())
     }
 }
 
 impl std::error::Error for Error {}
 
+/// Style de rendu des diagnostics
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderStyle {
+    /// Couleurs ANSI pour le terminal
+    Color,
+    /// Texte brut (navigateur, fichiers, tests)
+    Plain,
+}
+
+impl RenderStyle {
+    fn paint(&self, code: &str, text: &str) -> String {
+        match self {
+            RenderStyle::Color => format!("\x1b[{}m{}\x1b[0m", code, text),
+            RenderStyle::Plain => text.to_string(),
+        }
+    }
+}
+
+const RED_BOLD: &str = "1;31";
+const BLUE_BOLD: &str = "1;34";
+const CYAN: &str = "36";
+
+impl Error {
+    /// Titre de l'erreur en haoussa
+    pub fn headline(&self) -> &'static str {
+        match self {
+            Error::LexError(..) => "Kuskure na Tokenization",
+            Error::ParseError(..) => "Kuskure na Syntax",
+            Error::RuntimeError(..) => "Kuskure na Runtime",
+            Error::Thrown(..) => "Kuskure da aka jefa",
+            Error::LimitExceeded(..) => "Kuskure na Iyaka",
+            Error::FileError(_) => "Kuskure na Fayil",
+        }
+    }
+
+    /// Message de l'erreur, sans titre ni position
+    pub fn message(&self) -> &str {
+        match self {
+            Error::LexError(msg, _, _)
+            | Error::ParseError(msg, _, _)
+            | Error::RuntimeError(msg, _, _, _)
+            | Error::Thrown(msg, _, _)
+            | Error::LimitExceeded(msg, _, _)
+            | Error::FileError(msg) => msg,
+        }
+    }
+
+    /// Position de l'erreur dans le code source, si connue
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            Error::LexError(_, span, _)
+            | Error::ParseError(_, span, _)
+            | Error::RuntimeError(_, span, _, _)
+            | Error::Thrown(_, span, _)
+            | Error::LimitExceeded(_, span, _) => *span,
+            Error::FileError(_) => None,
+        }
+    }
+
+    /// Pile des appels au moment de l'erreur, de l'appel le plus récent au plus ancien
+    pub fn traceback(&self) -> &[Frame] {
+        match self {
+            Error::RuntimeError(_, _, frames, _)
+            | Error::Thrown(_, _, frames)
+            | Error::LimitExceeded(_, _, frames) => frames,
+            _ => &[],
+        }
+    }
+
+    /// Attache la pile des appels si l'erreur n'en a pas encore
+    ///
+    /// Comme pour la position, c'est la pile la plus profonde qui est conservée.
+    pub fn with_traceback(self, frames: &[Frame]) -> Self {
+        match self {
+            Error::RuntimeError(msg, span, traceback, code) if traceback.is_empty() => {
+                Error::RuntimeError(msg, span, frames.iter().rev().cloned().collect(), code)
+            }
+            Error::Thrown(msg, span, traceback) if traceback.is_empty() => {
+                Error::Thrown(msg, span, frames.iter().rev().cloned().collect())
+            }
+            Error::LimitExceeded(msg, span, traceback) if traceback.is_empty() => {
+                Error::LimitExceeded(msg, span, frames.iter().rev().cloned().collect())
+            }
+            other => other,
+        }
+    }
+
+    /// Nature de l'erreur, telle que la voit `kuskure.iri()` dans un bloc kama
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Error::LexError(..) => "Tokenization",
+            Error::ParseError(..) => "Syntax",
+            Error::RuntimeError(..) => "Runtime",
+            Error::Thrown(..) => "Jefa",
+            Error::LimitExceeded(..) => "Iyaka",
+            Error::FileError(_) => "Fayil",
+        }
+    }
+
+    /// Vérifie si un bloc `gwada` peut rattraper l'erreur
+    ///
+    /// Toutes les erreurs d'exécution le peuvent, sauf l'épuisement du budget ou du
+    /// délai : un programme encadré ne doit pas pouvoir s'y soustraire. Les erreurs
+    /// de syntaxe surviennent avant que le programme ne démarre.
+    pub fn is_catchable(&self) -> bool {
+        matches!(self, Error::RuntimeError(..) | Error::Thrown(..))
+    }
+
+    /// Code de l'erreur; `Generic` pour les erreurs sans code
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::LexError(_, _, code)
+            | Error::ParseError(_, _, code)
+            | Error::RuntimeError(_, _, _, code) => *code,
+            _ => ErrorCode::Generic,
+        }
+    }
+
+    /// Conseil en haoussa pour corriger les erreurs les plus courantes
+    pub fn hint(&self) -> Option<&'static str> {
+        if let Error::LimitExceeded(..) = self {
+            return Some("Duba ko akwai madauki marar ƙarshe, misali maimaita (gaskiya) ba tare da katse ba");
+        }
+
+        match self.code() {
+            ErrorCode::UnterminatedString => Some("Ka rufe jimlar da alamar '\"'"),
+            ErrorCode::UnknownToken => Some("Wannan alamar ba ta cikin yaren Dabara"),
+            ErrorCode::IntegerLiteralTooLarge => {
+                Some("Lambobi cikakku ba za su wuce 9223372036854775807 ba; rubuta lambar mai daɗewa (misali 100000000000000000000.0)")
+            }
+            ErrorCode::MissingBegin => Some("Kowane shiri yana farawa da 'fara' kuma yana ƙarewa da 'ƙare'"),
+            ErrorCode::UnexpectedEof => {
+                Some("Shirin ya ƙare ba zato ba tsammani: duba ko ka rufe kowane '{', '(' da '[', kuma ka rubuta 'ƙare'")
+            }
+            ErrorCode::ReservedWord => {
+                Some("Zaɓi wani suna, misali 'da_shi' maimakon 'da'; kalmomin yaren Dabara ba su zama sunaye ba")
+            }
+            ErrorCode::NestingTooDeep => {
+                Some("Raba lissafin zuwa ƙananan sassa da masu canji (var) maimakon baka a cikin baka")
+            }
+            ErrorCode::VariableNotFound => Some("Ka ƙirƙiri mai canjin da 'var' kafin ka yi amfani da shi"),
+            ErrorCode::FunctionNotFound => Some("Ka rubuta 'aiki suna(...) { ... }' kafin ka kira shi"),
+            ErrorCode::DivisionByZero => Some("Duba cewa mai rabawa ba sifili ba ne kafin ka raba"),
+            ErrorCode::DecimalFloatMix => {
+                Some("Kuɗi ba ya haɗuwa da lambar mai daɗewa: rubuta kudi(0.5) maimakon 0.5")
+            }
+            ErrorCode::TooManyDecimalPlaces => {
+                Some("Zagaye kuɗin da zagaye_kudi(x, 2) kafin ka ninka shi, don rage wurare bayan digo")
+            }
+            ErrorCode::IntegerOverflow => {
+                Some("Lambobi cikakku suna tsakanin -9223372036854775808 da 9223372036854775807; yi amfani da lambar mai daɗewa (misali 25.0) don manyan lambobi")
+            }
+            ErrorCode::IndexOutOfBounds => {
+                Some("Lambobin jeri suna farawa daga 0 har zuwa tsawo - 1; -1 yana nufin na ƙarshe")
+            }
+            ErrorCode::RecursionTooDeep => {
+                Some("Aikin da ke kiran kansa yana buƙatar yanayin tsayawa, misali: idan n == 0 { mayar 1 }")
+            }
+            ErrorCode::EndOfInput => {
+                Some("Sanya karɓa cikin gwada { ... } kama { ... } don sarrafa ƙarshen shigarwa")
+            }
+            ErrorCode::ScriptedInputExhausted => {
+                Some("Ƙara layuka a shigarwar da aka shirya (--input): layi ɗaya ga kowane karɓa")
+            }
+            ErrorCode::UnexplainedAssertion => {
+                Some("Ƙara saƙo don bayyana abin da ake tsammani: tabbatar x > 0, \"x ya zama tabbatacce\"")
+            }
+            ErrorCode::Generic => None,
+        }
+    }
+
+    /// Produit un diagnostic complet : titre, position, ligne de code
+    /// soulignée, pile des appels et conseil éventuel.
+    ///
+    /// ```text
+    /// Kuskure na Runtime: Ba za a iya raba da sifili ba (Division par zéro)
+    ///   --> lissafi.ha:3:14
+    ///    |
+    ///  3 |     mayar a / b
+    ///    |             ^
+    ///    = tarihin kira (traceback), na baya-bayan nan a farko:
+    ///        a cikin 'raba', an kira a lissafi.ha:7:5
+    ///    = shawara: ...
+    /// ```
+    pub fn render(&self, source: &str, filename: &str, style: RenderStyle) -> String {
+        let mut output = format!(
+            "{}: {}",
+            style.paint(RED_BOLD, self.headline()),
+            self.message()
+        );
+
+        let gutter = match self.span() {
+            Some(span) => {
+                let gutter = " ".repeat(span.line.to_string().len());
+                output.push_str(&render_location(span, source, filename, &gutter, style));
+                gutter
+            }
+            None => String::new(),
+        };
+
+        if !self.traceback().is_empty() {
+            output.push_str(&format!(
+                "\n{} {}",
+                gutter,
+                style.paint(CYAN, "= tarihin kira (traceback), na baya-bayan nan a farko:")
+            ));
+            for line in traceback_lines(self.traceback(), filename) {
+                output.push_str(&format!("\n{}     {}", gutter, line));
+            }
+        }
+
+        if let Some(hint) = self.hint() {
+            output.push_str(&format!("\n{} {} {}", gutter, style.paint(CYAN, "= shawara:"), hint));
+        }
+
+        output
+    }
+
+    /// Attache une position à l'erreur si elle n'en a pas encore
+    ///
+    /// La position la plus interne est conservée : une erreur déjà localisée
+    /// n'est pas déplacée par les appelants.
+    pub fn with_span(self, span: Span) -> Self {
+        match self {
+            Error::LexError(msg, None, code) => Error::LexError(msg, Some(span), code),
+            Error::ParseError(msg, None, code) => Error::ParseError(msg, Some(span), code),
+            Error::RuntimeError(msg, None, frames, code) => Error::RuntimeError(msg, Some(span), frames, code),
+            Error::Thrown(msg, None, frames) => Error::Thrown(msg, Some(span), frames),
+            Error::LimitExceeded(msg, None, frames) => Error::LimitExceeded(msg, Some(span), frames),
+            other => other,
+        }
+    }
+}
+
 /// Messages d'erreur en haoussa
 impl Error {
     pub fn unknown_token(token: &str) -> Self {
-        Error::LexError(format!("Ba a gane kalmar '{}'", token))
+        Error::LexError(format!("Ba a gane kalmar '{}'", token), None, ErrorCode::UnknownToken)
+    }
+
+    pub fn integer_literal_too_large(literal: &str) -> Self {
+        Error::LexError(format!(
+            "Lambar '{}' ta yi girma da yawa (integer literal too large)",
+            literal
+        ), None, ErrorCode::IntegerLiteralTooLarge)
     }
     
     pub fn unexpected_token(expected: &str, found: &str) -> Self {
-        Error::ParseError(format!("Ana tsammanin '{}', amma an samu '{}'", expected, found))
+        let code = match (expected, found) {
+            ("Begin", _) => ErrorCode::MissingBegin,
+            (_, "Eof") => ErrorCode::UnexpectedEof,
+            _ => ErrorCode::Generic,
+        };
+        Error::ParseError(format!("Ana tsammanin '{}', amma an samu '{}'", expected, found), None, code)
     }
     
     pub fn variable_not_found(name: &str) -> Self {
-        Error::RuntimeError(format!("Babu irin wannan mai canjin '{}' da aka rubuta", name))
+        Error::runtime_with_code(
+            format!("Babu irin wannan mai canjin '{}' da aka rubuta", name),
+            ErrorCode::VariableNotFound,
+        )
+    }
+
+    pub fn function_not_found(name: &str) -> Self {
+        Error::runtime_with_code(
+            format!("Fonction '{}' ba a gani ba (fonction non trouvée)", name),
+            ErrorCode::FunctionNotFound,
+        )
     }
     
     pub fn invalid_operation(op: &str, left_type: &str, right_type: &str) -> Self {
-        Error::RuntimeError(format!(
+        let code = match (left_type, right_type) {
+            ("kuɗi", "lambar mai daɗewa") | ("lambar mai daɗewa", "kuɗi") => ErrorCode::DecimalFloatMix,
+            _ => ErrorCode::Generic,
+        };
+        Error::runtime_with_code(format!(
             "Ba za a iya amfani da '{}' tsakanin {} da {}",
             op, left_type, right_type
-        ))
+        ), code)
     }
     
     pub fn file_not_found(filename: &str) -> Self {
@@ -57,18 +390,209 @@ impl Error {
     }
     
     pub fn unterminated_string() -> Self {
-        Error::LexError("Babu ƙarshen jimlar da aka rubuta".to_string())
+        Error::LexError("Babu ƙarshen jimlar da aka rubuta".to_string(), None, ErrorCode::UnterminatedString)
     }
     
     pub fn expected_statement() -> Self {
-        Error::ParseError("Ana tsammanin statement".to_string())
+        Error::parse_error("Ana tsammanin statement")
     }
     
     pub fn expected_expression() -> Self {
-        Error::ParseError("Ana tsammanin expression".to_string())
+        Error::parse_error("Ana tsammanin expression")
+    }
+    
+    pub fn invalid_assignment_target() -> Self {
+        Error::parse_error("Ba za a iya sanya ƙima a nan ba (cible d'affectation invalide)")
+    }
+    
+    pub fn integer_overflow(op: &str) -> Self {
+        Error::runtime_with_code(format!(
+            "Lambar ta yi girma da yawa: sakamakon '{}' ya wuce iyakar lambobi (integer overflow)",
+            op
+        ), ErrorCode::IntegerOverflow)
+    }
+    
+    pub fn too_many_decimal_places(limit: u32) -> Self {
+        Error::runtime_with_code(format!(
+            "Wurare bayan digo ba za su wuce {} ba (too many decimal places)",
+            limit
+        ), ErrorCode::TooManyDecimalPlaces)
+    }
+    
+    pub fn division_by_zero() -> Self {
+        Error::runtime_with_code("Ba za a iya raba da sifili ba (Division par zéro)".to_string(), ErrorCode::DivisionByZero)
     }
     
+    pub fn index_out_of_bounds(index: i64, len: u64) -> Self {
+        Error::runtime_with_code(format!(
+            "Lamba {} ya wuce iyaka: akwai abubuwa {} kawai (Index out of bounds)",
+            index, len
+        ), ErrorCode::IndexOutOfBounds)
+    }
+    
+    pub fn recursion_too_deep(function: &str, limit: usize) -> Self {
+        Error::runtime_with_code(format!(
+            "Kira ya yi zurfi da yawa: aiki '{}' ya wuce iyakar kira {} a jere (recursion too deep: maximum call depth exceeded)",
+            function, limit
+        ), ErrorCode::RecursionTooDeep)
+    }
+
+    pub fn step_limit_exceeded(limit: u64) -> Self {
+        Error::LimitExceeded(format!(
+            "Shirin ya wuce iyakar matakai {} (step budget exhausted)",
+            limit
+        ), None, Vec::new())
+    }
+
+    pub fn time_limit_exceeded(limit_ms: u128) -> Self {
+        Error::LimitExceeded(format!(
+            "Lokaci ya ƙare: shirin ya wuce {} ms (time limit exceeded)",
+            limit_ms
+        ), None, Vec::new())
+    }
+
+    pub fn end_of_input() -> Self {
+        Error::runtime_with_code(
+            "Shigarwa ta ƙare: babu sauran abin da za a karɓa (end of input)".to_string(),
+            ErrorCode::EndOfInput,
+        )
+    }
+
+    pub fn scripted_input_exhausted(lines: usize) -> Self {
+        Error::runtime_with_code(format!(
+            "Shigarwar da aka shirya ta ƙare: an riga an karɓa duka layuka {} (scripted input exhausted, {} lines read)",
+            lines, lines
+        ), ErrorCode::ScriptedInputExhausted)
+    }
+
+    pub fn reserved_word(word: &str) -> Self {
+        Error::ParseError(format!(
+            "'{}' kalma ce ta musamman a Dabara, ba za a iya amfani da ita a matsayin suna ba (reserved word used as a name)",
+            word
+        ), None, ErrorCode::ReservedWord)
+    }
+
+    pub fn nested_test_block() -> Self {
+        Error::parse_error(
+            "Ana iya rubuta gwaji a saman shiri kawai, ba a cikin wani aiki ko madauki ba (test blocks must be at the top level)",
+        )
+    }
+
+    pub fn assertion_failed(detail: Option<&str>) -> Self {
+        match detail {
+            Some(detail) => Error::runtime_error(&format!("Tabbatarwa ta kasa: {} (assertion failed)", detail)),
+            None => Error::runtime_with_code(
+                "Tabbatarwa ta kasa (assertion failed)".to_string(),
+                ErrorCode::UnexplainedAssertion,
+            ),
+        }
+    }
+
+    pub fn nesting_too_deep(limit: usize) -> Self {
+        Error::ParseError(format!(
+            "Rubutun ya yi zurfi da yawa: an wuce matakai {} na ciki-da-ciki (nesting too deep)",
+            limit
+        ), None, ErrorCode::NestingTooDeep)
+    }
+
+    pub fn thrown(message: &str) -> Self {
+        Error::Thrown(message.to_string(), None, Vec::new())
+    }
+
     pub fn runtime_error(message: &str) -> Self {
-        Error::RuntimeError(message.to_string())
+        Error::runtime_with_code(message.to_string(), ErrorCode::Generic)
+    }
+
+    pub fn parse_error(message: &str) -> Self {
+        Error::ParseError(message.to_string(), None, ErrorCode::Generic)
+    }
+
+    fn runtime_with_code(message: String, code: ErrorCode) -> Self {
+        Error::RuntimeError(message, None, Vec::new(), code)
+    }
+}
+
+/// Position et ligne de code soulignée d'un diagnostic
+fn render_location(span: Span, source: &str, filename: &str, gutter: &str, style: RenderStyle) -> String {
+    let mut output = format!(
+        "\n{}{} {}:{}:{}",
+        gutter,
+        style.paint(BLUE_BOLD, "-->"),
+        filename,
+        span.line,
+        span.column
+    );
+
+    if let Some(line) = source.lines().nth(span.line.saturating_sub(1)) {
+        let bar = style.paint(BLUE_BOLD, "|");
+
+        // Aligner le soulignement en conservant les tabulations de la ligne
+        let padding: String = line.chars()
+            .take(span.column.saturating_sub(1))
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let remaining = line.chars().count().saturating_sub(span.column - 1);
+        let width = span.len.min(remaining).max(1);
+
+        output.push_str(&format!("\n{} {}", gutter, bar));
+        output.push_str(&format!(
+            "\n{} {} {}",
+            style.paint(BLUE_BOLD, &span.line.to_string()),
+            bar,
+            line
+        ));
+        output.push_str(&format!(
+            "\n{} {} {}{}",
+            gutter,
+            bar,
+            padding,
+            style.paint(RED_BOLD, &"^".repeat(width))
+        ));
     }
-}
\ No newline at end of file
+
+    output
+}
+
+/// Nombre de répétitions identiques affichées avant de les résumer
+const TRACEBACK_REPEATS: usize = 3;
+
+/// Nombre de lignes affichées au début et à la fin d'une pile trop longue
+const TRACEBACK_EDGE: usize = 10;
+
+/// Lignes de la pile des appels
+///
+/// Les appels identiques consécutifs (récursion) sont résumés, puis le milieu
+/// d'une pile encore trop longue est abrégé.
+fn traceback_lines(frames: &[Frame], filename: &str) -> Vec<String> {
+    let describe = |frame: &Frame| match frame.call_site {
+        Some(span) => format!(
+            "a cikin '{}', an kira a {}:{}:{}",
+            frame.function, filename, span.line, span.column
+        ),
+        None => format!("a cikin '{}'", frame.function),
+    };
+
+    let mut lines = Vec::new();
+    let mut index = 0;
+    while index < frames.len() {
+        let repeats = frames[index..].iter().take_while(|frame| **frame == frames[index]).count();
+        let shown = repeats.min(TRACEBACK_REPEATS);
+        lines.extend(std::iter::repeat_n(describe(&frames[index]), shown));
+        if repeats > shown {
+            let hidden = repeats - shown;
+            lines.push(format!("... an maimaita sau {} (repeated {} more times)", hidden, hidden));
+        }
+        index += repeats;
+    }
+
+    if lines.len() <= 2 * TRACEBACK_EDGE {
+        return lines;
+    }
+
+    let hidden = lines.len() - 2 * TRACEBACK_EDGE;
+    let tail = lines.split_off(lines.len() - TRACEBACK_EDGE);
+    lines.truncate(TRACEBACK_EDGE);
+    lines.push(format!("... an ɓoye wasu layuka {} ({} more lines)", hidden, hidden));
+    lines.extend(tail);
+    lines
+}
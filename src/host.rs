@@ -0,0 +1,225 @@
+//! Entrées et sorties d'un programme Dabara
+//!
+//! L'interpréteur n'écrit ni ne lit jamais directement le terminal : `rubuta`
+//! et `karɓa` passent par un [`Host`]. Le terminal ([`StdHost`]), un tampon en
+//! mémoire ([`BufferHost`], pour les tests et les outils) et le navigateur
+//! (`wasm::JsHost`) en sont des implémentations. [`ScriptedHost`] rejoue une
+//! entrée préparée d'avance par-dessus n'importe lequel d'entre eux.
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::io::{self, BufRead, Write};
+use std::rc::Rc;
+
+use crate::error::Error;
+
+/// Environnement d'entrées et sorties d'un programme
+pub trait Host {
+    /// Écrit du texte tel quel (`rubuta` ajoute lui-même le retour à la ligne)
+    fn write(&mut self, text: &str);
+
+    /// Affiche une invite juste avant une lecture
+    ///
+    /// Par défaut l'invite est écrite comme une sortie ordinaire.
+    fn prompt(&mut self, text: &str) {
+        self.write(text);
+    }
+
+    /// Lit une ligne, sans son retour à la ligne
+    ///
+    /// Retourne `Ok(None)` à la fin de l'entrée.
+    fn read_line(&mut self) -> Result<Option<String>, Error>;
+}
+
+/// Le terminal : sortie standard et entrée standard
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StdHost;
+
+impl Host for StdHost {
+    fn write(&mut self, text: &str) {
+        print!("{}", text);
+    }
+
+    fn prompt(&mut self, text: &str) {
+        print!("{}", text);
+        // L'invite n'a pas de retour à la ligne : il faut vider le tampon soi-même
+        let _ = io::stdout().flush();
+    }
+
+    fn read_line(&mut self) -> Result<Option<String>, Error> {
+        let mut line = String::new();
+        match io::stdin().lock().read_line(&mut line) {
+            Ok(0) => Ok(None),
+            Ok(_) => Ok(Some(strip_line_ending(line))),
+            Err(_) => Err(Error::runtime_error("Ba za a iya karba shigarwa ba (Impossible de lire l'entrée)")),
+        }
+    }
+}
+
+/// Entrées et sorties en mémoire
+///
+/// Les clones partagent le même tampon : on garde un clone pour lire la sortie
+/// après avoir confié l'autre à l'interpréteur.
+///
+/// ```
+/// use dabara::host::BufferHost;
+/// use dabara::{parse, tokenize, Interpreter};
+///
+/// let host = BufferHost::with_input(["Musa"]);
+/// let mut interpreter = Interpreter::new();
+/// interpreter.set_host(Box::new(host.clone()));
+///
+/// let program = parse(tokenize("fara\nvar suna = karɓa\nrubuta \"Sannu \" + suna\nƙare").unwrap()).unwrap();
+/// interpreter.execute(program).unwrap();
+///
+/// assert_eq!(host.output(), "Rubuta abu: Sannu Musa\n");
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct BufferHost {
+    output: Rc<RefCell<String>>,
+    input: Rc<RefCell<VecDeque<String>>>,
+}
+
+impl BufferHost {
+    /// Tampon vide, sans entrée à lire
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tampon dont les lectures reçoivent ces lignes, dans l'ordre
+    pub fn with_input<I, S>(lines: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let host = Self::new();
+        host.push_input(lines);
+        host
+    }
+
+    /// Ajoute des lignes à lire
+    pub fn push_input<I, S>(&self, lines: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.input.borrow_mut().extend(lines.into_iter().map(Into::into));
+    }
+
+    /// Tout ce qui a été écrit jusqu'ici
+    pub fn output(&self) -> String {
+        self.output.borrow().clone()
+    }
+
+    /// Retire et retourne ce qui a été écrit jusqu'ici
+    pub fn take_output(&self) -> String {
+        std::mem::take(&mut *self.output.borrow_mut())
+    }
+}
+
+impl Host for BufferHost {
+    fn write(&mut self, text: &str) {
+        self.output.borrow_mut().push_str(text);
+    }
+
+    fn read_line(&mut self) -> Result<Option<String>, Error> {
+        Ok(self.input.borrow_mut().pop_front())
+    }
+}
+
+/// Entrée préparée d'avance, pour des exécutions reproductibles
+///
+/// Chaque lecture reçoit la ligne suivante; la sortie passe par l'hôte enveloppé.
+/// Une fois les lignes épuisées, `karɓa` échoue avec une erreur qui dit combien de
+/// lignes ont été lues. Avec la transcription, chaque réponse est écrite après son
+/// invite, comme si on l'avait tapée.
+///
+/// ```
+/// use dabara::host::{BufferHost, ScriptedHost};
+/// use dabara::{parse, tokenize, Interpreter};
+///
+/// let output = BufferHost::new();
+/// let mut interpreter = Interpreter::new();
+/// interpreter.set_host(Box::new(ScriptedHost::new(output.clone(), ["Musa"]).with_transcript(true)));
+///
+/// let program = parse(tokenize("fara\nvar suna = karɓa(\"Suna? \")\nrubuta \"Sannu \" + suna\nƙare").unwrap()).unwrap();
+/// interpreter.execute(program).unwrap();
+///
+/// assert_eq!(output.output(), "Suna? Musa\nSannu Musa\n");
+/// ```
+#[derive(Debug, Clone)]
+pub struct ScriptedHost<H> {
+    inner: H,
+    lines: VecDeque<String>,
+    read: usize,
+    transcript: bool,
+}
+
+impl<H: Host> ScriptedHost<H> {
+    /// Hôte dont les lectures reçoivent ces lignes, dans l'ordre
+    pub fn new<I, S>(inner: H, lines: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        ScriptedHost {
+            inner,
+            lines: lines.into_iter().map(Into::into).collect(),
+            read: 0,
+            transcript: false,
+        }
+    }
+
+    /// Hôte dont les lectures reçoivent les lignes de ce texte (`\n` ou `\r\n`)
+    pub fn from_text(inner: H, text: &str) -> Self {
+        Self::new(inner, text.lines())
+    }
+
+    /// Écrit ou non chaque réponse après son invite
+    pub fn with_transcript(mut self, transcript: bool) -> Self {
+        self.transcript = transcript;
+        self
+    }
+
+    /// Nombre de lignes pas encore lues
+    pub fn remaining(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// L'hôte enveloppé
+    pub fn inner(&self) -> &H {
+        &self.inner
+    }
+}
+
+impl<H: Host> Host for ScriptedHost<H> {
+    fn write(&mut self, text: &str) {
+        self.inner.write(text);
+    }
+
+    fn prompt(&mut self, text: &str) {
+        self.inner.prompt(text);
+    }
+
+    fn read_line(&mut self) -> Result<Option<String>, Error> {
+        let line = self.lines.pop_front().ok_or_else(|| Error::scripted_input_exhausted(self.read))?;
+        self.read += 1;
+
+        if self.transcript {
+            self.inner.write(&line);
+            self.inner.write("\n");
+        }
+        Ok(Some(line))
+    }
+}
+
+/// Retire le retour à la ligne final (`\n` ou `\r\n`)
+fn strip_line_ending(mut line: String) -> String {
+    if line.ends_with('\n') {
+        line.pop();
+        if line.ends_with('\r') {
+            line.pop();
+        }
+    }
+    line
+}
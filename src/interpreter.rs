@@ -3,21 +3,114 @@
 //! Ce module implémente le moteur d'exécution qui évalue l'AST
 //! et gère l'environnement des variables.
 
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fmt;
-use std::io::{self, Write};
+use std::rc::Rc;
+use std::time::Duration;
 
-use crate::parser::{Program, Statement, Expression, BinaryOperator, UnaryOperator};
-use crate::error::Error;
+use crate::decimal::{Decimal, RoundingMode, DEFAULT_DIVISION_SCALE};
+use crate::parser::{Program, Statement, StatementKind, Expression, ExpressionKind, BinaryOperator, CompoundOperator, UnaryOperator};
+use crate::error::{Error, Frame};
+use crate::host::{BufferHost, Host, StdHost};
+use crate::lexer::Span;
+use crate::stdlib;
 
 #[cfg(feature = "serde")]
 use serde::Serialize;
 
-/// Représente une fonction définie par l'utilisateur
-#[derive(Debug, Clone)]
+/// Scope local partagé: une fermeture garde une référence vers les scopes où elle est née
+type Scope = Rc<RefCell<HashMap<String, Value>>>;
+
+/// Représente une fonction définie par l'utilisateur (nommée ou anonyme)
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Function {
+    /// Nom de la fonction (`None` pour `aiki(x) { ... }`)
+    pub name: Option<String>,
     pub parameters: Vec<String>,
     pub body: Vec<Statement>,
+    /// Scopes locaux visibles là où la fonction a été définie (portée lexicale)
+    ///
+    /// Vide pour une fonction définie au niveau global: elle ne voit que les globales.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    environment: Vec<Scope>,
+}
+
+impl Function {
+    /// Nom affiché dans les messages (« aiki » pour une fonction anonyme)
+    pub fn display_name(&self) -> &str {
+        self.name.as_deref().unwrap_or("aiki")
+    }
+}
+
+impl fmt::Debug for Function {
+    /// L'environnement capturé n'est pas affiché: il peut contenir la fonction elle-même
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Function")
+            .field("name", &self.name)
+            .field("parameters", &self.parameters)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Signature d'une fonction native (implémentée en Rust)
+pub type NativeFn = fn(&[Value]) -> Result<Value, Error>;
+
+/// Signature d'une fonction native qui rappelle l'interpréteur (map, filter, ...)
+pub type HigherOrderFn = fn(&mut Interpreter, &[Value]) -> Result<Value, Error>;
+
+/// Signature d'une fonction native qui lit ou écrit par l'hôte (karɓa_lamba, ...)
+pub type HostFn = fn(&mut Interpreter, &[Value]) -> Result<Value, Error>;
+
+/// Implémentation d'une fonction native
+#[derive(Debug, Clone, Copy)]
+pub enum NativeBody {
+    /// Calcule son résultat à partir des arguments seulement
+    Simple(NativeFn),
+    /// Peut appeler les fonctions reçues en argument
+    HigherOrder(HigherOrderFn),
+    /// Passe par l'hôte de l'interpréteur sans rappeler de code Dabara
+    Host(HostFn),
+}
+
+/// Nombre d'arguments accepté par une fonction native
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Arity {
+    /// Exactement n arguments
+    Exact(usize),
+    /// Au moins n arguments (fonctions variadiques comme `min`/`max`)
+    AtLeast(usize),
+    /// Entre min et max arguments, bornes comprises (argument facultatif)
+    Range(usize, usize),
+}
+
+impl Arity {
+    /// Vérifie si le nombre d'arguments donné est accepté
+    pub fn accepts(&self, count: usize) -> bool {
+        match self {
+            Arity::Exact(n) => count == *n,
+            Arity::AtLeast(n) => count >= *n,
+            Arity::Range(min, max) => (*min..=*max).contains(&count),
+        }
+    }
+}
+
+impl fmt::Display for Arity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Arity::Exact(n) => write!(f, "{}", n),
+            Arity::AtLeast(n) => write!(f, "aƙalla {}", n),
+            Arity::Range(min, max) => write!(f, "{} zuwa {}", min, max),
+        }
+    }
+}
+
+/// Représente une fonction native de la bibliothèque standard
+#[derive(Debug, Clone)]
+pub struct NativeFunction {
+    pub arity: Arity,
+    pub func: NativeBody,
 }
 
 /// Types de valeurs dans Dabara
@@ -28,29 +121,192 @@ pub enum Value {
     Number(i64),
     /// Nombre flottant
     Float(f64),
+    /// Nombre décimal exact (kuɗi): kudi("12.50")
+    Decimal(Decimal),
     /// Chaîne de caractères
     String(String),
     /// Valeur booléenne
     Boolean(bool),
-    /// Liste de valeurs
-    List(Vec<Value>),
+    /// Liste de valeurs, partagée par référence
+    List(Rc<RefCell<Vec<Value>>>),
+    /// Dictionnaire (ordre d'insertion conservé), partagé par référence
+    Map(Rc<RefCell<OrderedMap>>),
+    /// Zango de lambobi, calculé à la demande: 1..10
+    Range(Range),
+    /// Fonction utilisée comme valeur: stockée, passée en argument ou retournée
+    Function(Rc<Function>),
+    /// Erreur rattrapée par un bloc kama (nature, message et position)
+    Error(Rc<Error>),
+    /// Fonction de la bibliothèque standard utilisée comme valeur: map(jeri, abs)
+    NativeFunction {
+        name: String,
+        #[cfg_attr(feature = "serde", serde(skip))]
+        function: NativeFunction,
+    },
 }
 
-impl Value {
-    /// Convertit une valeur en chaîne pour l'affichage
-    pub fn to_string(&self) -> String {
-        match self {
-            Value::Number(n) => n.to_string(),
-            Value::Float(f) => f.to_string(),
-            Value::String(s) => s.clone(),
-            Value::Boolean(b) => if *b { "gaskiya".to_string() } else { "karya".to_string() },
-            Value::List(elements) => {
-                let strings: Vec<String> = elements.iter()
-                    .map(|v| v.to_string())
-                    .collect();
-                format!("[{}]", strings.join(", "))
-            },
+/// Intervalle d'entiers paresseux: les éléments ne sont jamais stockés
+///
+/// `1..1000000` occupe trois entiers, quelle que soit sa longueur.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Range {
+    pub start: i64,
+    pub end: i64,
+    pub inclusive: bool,
+    pub step: i64,
+}
+
+impl Range {
+    /// Crée un intervalle; le pas ne peut pas être nul
+    pub fn new(start: i64, end: i64, inclusive: bool, step: i64) -> Result<Range, Error> {
+        if step == 0 {
+            return Err(Error::runtime_error("Taki na zango ba zai iya zama sifili ba (range step cannot be zero)"));
+        }
+        Ok(Range { start, end, inclusive, step })
+    }
+
+    /// Nombre d'éléments (calculé en i128 pour éviter les dépassements)
+    pub fn len(&self) -> i128 {
+        let (start, end, step) = (self.start as i128, self.end as i128, self.step as i128);
+        // Distance jusqu'au dernier élément possible, dans le sens du pas
+        let span = if step > 0 { end - start } else { start - end };
+        let span = if self.inclusive { span } else { span - 1 };
+        if span < 0 {
+            0
+        } else {
+            span / step.abs() + 1
+        }
+    }
+
+    /// Vérifie si l'intervalle est vide
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Élément à la position donnée (les indices négatifs partent de la fin)
+    pub fn get(&self, index: i64) -> Option<i64> {
+        let len = self.len();
+        let position = if index < 0 { len + index as i128 } else { index as i128 };
+        if position < 0 || position >= len {
+            return None;
+        }
+        i64::try_from(self.start as i128 + position * self.step as i128).ok()
+    }
+
+    /// Vérifie si une valeur fait partie de l'intervalle
+    pub fn contains(&self, value: i64) -> bool {
+        let offset = value as i128 - self.start as i128;
+        let step = self.step as i128;
+        offset % step == 0 && offset / step >= 0 && offset / step < self.len()
+    }
+
+    /// Parcourt les éléments sans les stocker
+    pub fn iter(&self) -> impl Iterator<Item = i64> {
+        let range = *self;
+        let len = self.len();
+        (0..len).map(move |i| (range.start as i128 + i * range.step as i128) as i64)
+    }
+}
+
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let operator = if self.inclusive { "..=" } else { ".." };
+        write!(f, "{}{}{}", self.start, operator, self.end)?;
+        if self.step != 1 {
+            write!(f, " taki {}", self.step)?;
         }
+        Ok(())
+    }
+}
+
+/// Dictionnaire qui conserve l'ordre d'insertion des clés
+///
+/// Les clés sont des jimla, des lambar ou des booléens.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct OrderedMap {
+    entries: Vec<(Value, Value)>,
+}
+
+impl OrderedMap {
+    /// Crée un dictionnaire vide
+    pub fn new() -> Self {
+        OrderedMap { entries: Vec::new() }
+    }
+
+    /// Vérifie qu'une valeur peut servir de clé
+    pub fn check_key(key: &Value) -> Result<(), Error> {
+        match key {
+            Value::String(_) | Value::Number(_) | Value::Boolean(_) => Ok(()),
+            other => Err(Error::runtime_error(&format!(
+                "Maɓalli dole ya zama jimla, lambar ko gaskiya/karya, ba {} ba (invalid map key)",
+                other.type_name()
+            ))),
+        }
+    }
+
+    /// Récupère la valeur associée à une clé
+    pub fn get(&self, key: &Value) -> Option<&Value> {
+        self.entries.iter()
+            .find(|(k, _)| k.equals(key))
+            .map(|(_, v)| v)
+    }
+
+    /// Insère ou remplace une valeur (une clé existante garde sa place)
+    pub fn insert(&mut self, key: Value, value: Value) {
+        match self.entries.iter_mut().find(|(k, _)| k.equals(&key)) {
+            Some(entry) => entry.1 = value,
+            None => self.entries.push((key, value)),
+        }
+    }
+
+    /// Retire une clé et retourne sa valeur
+    pub fn remove(&mut self, key: &Value) -> Option<Value> {
+        let position = self.entries.iter().position(|(k, _)| k.equals(key))?;
+        Some(self.entries.remove(position).1)
+    }
+
+    /// Vérifie si une clé est présente
+    pub fn contains_key(&self, key: &Value) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Clés dans l'ordre d'insertion
+    pub fn keys(&self) -> impl Iterator<Item = &Value> {
+        self.entries.iter().map(|(k, _)| k)
+    }
+
+    /// Valeurs dans l'ordre d'insertion
+    pub fn values(&self) -> impl Iterator<Item = &Value> {
+        self.entries.iter().map(|(_, v)| v)
+    }
+
+    /// Paires (clé, valeur) dans l'ordre d'insertion
+    pub fn iter(&self) -> impl Iterator<Item = &(Value, Value)> {
+        self.entries.iter()
+    }
+
+    /// Nombre d'entrées
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Vérifie si le dictionnaire est vide
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl Value {
+    /// Crée une nouvelle liste
+    pub fn list(elements: Vec<Value>) -> Value {
+        Value::List(Rc::new(RefCell::new(elements)))
+    }
+
+    /// Crée un nouveau dictionnaire
+    pub fn map(map: OrderedMap) -> Value {
+        Value::Map(Rc::new(RefCell::new(map)))
     }
 
     /// Retourne le type de la valeur pour les messages d'erreur
@@ -58,27 +314,174 @@ impl Value {
         match self {
             Value::Number(_) => "lambar",
             Value::Float(_) => "lambar mai daɗewa",
+            Value::Decimal(_) => "kuɗi",
             Value::String(_) => "jimla",
             Value::Boolean(_) => "gaskiya ko karya",
             Value::List(_) => "jerin abu",
+            Value::Map(_) => "ƙamus",
+            Value::Range(_) => "zango",
+            Value::Function(_) | Value::NativeFunction { .. } => "aiki",
+            Value::Error(_) => "kuskure",
+        }
+    }
+
+    /// Évalue la valeur comme booléenne (conditions de idan / maimaita)
+    pub fn is_truthy(&self) -> bool {
+        match self {
+            Value::Boolean(b) => *b,
+            Value::Number(n) => *n != 0,
+            Value::Float(f) => *f != 0.0 && !f.is_nan(),
+            Value::Decimal(d) => !d.is_zero(),
+            Value::String(s) => !s.is_empty(),
+            Value::List(l) => !l.borrow().is_empty(),
+            Value::Map(m) => !m.borrow().is_empty(),
+            Value::Range(r) => !r.is_empty(),
+            Value::Function(_) | Value::NativeFunction { .. } | Value::Error(_) => true,
+        }
+    }
+
+    /// Égalité structurelle entre deux valeurs (utilisée par == et les clés)
+    ///
+    /// Les dictionnaires sont égaux s'ils ont les mêmes paires, quel que soit l'ordre.
+    pub fn equals(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::Number(a), Value::Number(b)) => a == b,
+            (Value::Float(a), Value::Float(b)) => (a - b).abs() < f64::EPSILON,
+            (Value::Decimal(a), Value::Decimal(b)) => a == b,
+            (Value::Decimal(a), Value::Number(b)) | (Value::Number(b), Value::Decimal(a)) => {
+                *a == Decimal::from_int(*b)
+            }
+            (Value::Number(a), Value::Float(b)) | (Value::Float(b), Value::Number(a)) => {
+                (*a as f64 - b).abs() < f64::EPSILON
+            }
+            (Value::String(a), Value::String(b)) => a == b,
+            (Value::Boolean(a), Value::Boolean(b)) => a == b,
+            (Value::List(a), Value::List(b)) => {
+                if Rc::ptr_eq(a, b) {
+                    return true;
+                }
+                let (a, b) = (a.borrow(), b.borrow());
+                a.len() == b.len() && a.iter().zip(b.iter()).all(|(x, y)| x.equals(y))
+            }
+            (Value::Range(a), Value::Range(b)) => {
+                // Deux zangon sont égaux s'ils produisent les mêmes lambobi
+                let len = a.len();
+                len == b.len() && (len == 0 || (a.start == b.start && (len == 1 || a.step == b.step)))
+            }
+            (Value::Function(a), Value::Function(b)) => Rc::ptr_eq(a, b),
+            (Value::Error(a), Value::Error(b)) => {
+                a.kind() == b.kind() && a.message() == b.message() && a.span() == b.span()
+            }
+            (Value::NativeFunction { name: a, .. }, Value::NativeFunction { name: b, .. }) => a == b,
+            (Value::Map(a), Value::Map(b)) => {
+                if Rc::ptr_eq(a, b) {
+                    return true;
+                }
+                let (a, b) = (a.borrow(), b.borrow());
+                a.len() == b.len()
+                    && a.iter().all(|(k, v)| b.get(k).is_some_and(|other| v.equals(other)))
+            }
+            _ => false,
         }
     }
 }
 
 impl fmt::Display for Value {
+    /// Convertit une valeur en chaîne pour l'affichage
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.to_string())
+        match self {
+            Value::Number(n) => write!(f, "{}", n),
+            Value::Float(x) => write!(f, "{}", x),
+            Value::Decimal(d) => write!(f, "{}", d),
+            Value::Range(r) => write!(f, "{}", r),
+            Value::Function(function) => match &function.name {
+                Some(name) => write!(f, "<aiki {}>", name),
+                None => write!(f, "<aiki>"),
+            },
+            Value::NativeFunction { name, .. } => write!(f, "<aiki {}>", name),
+            Value::Error(error) => write!(f, "{}", error),
+            Value::String(s) => write!(f, "{}", s),
+            Value::Boolean(b) => write!(f, "{}", if *b { "gaskiya" } else { "karya" }),
+            Value::List(elements) => {
+                let strings: Vec<String> = elements.borrow().iter()
+                    .map(|v| v.to_string())
+                    .collect();
+                write!(f, "[{}]", strings.join(", "))
+            }
+            Value::Map(map) => {
+                let strings: Vec<String> = map.borrow().iter()
+                    .map(|(k, v)| format!("{}: {}", k, v))
+                    .collect();
+                write!(f, "{{{}}}", strings.join(", "))
+            }
+        }
     }
 }
 
 /// Interpréteur pour exécuter les programmes Dabara
 pub struct Interpreter {
-    /// Stack de scopes pour les variables (0 = global, top = local)
-    scope_stack: Vec<HashMap<String, Value>>,
+    /// Variables globales
+    globals: HashMap<String, Value>,
+    /// Stack de scopes locaux (vide au niveau global, top = le plus local)
+    scope_stack: Vec<Scope>,
+    /// Indice du premier scope de la fonction en cours (`None` hors de toute fonction)
+    frame_base: Option<usize>,
     /// Fonctions définies par l'utilisateur
     pub functions: HashMap<String, Function>,
+    /// Fonctions natives de la bibliothèque standard
+    pub native_functions: HashMap<String, NativeFunction>,
     /// Control flow state for loops
     loop_control: Option<LoopControl>,
+    /// Pile des appels en cours (le plus récent en dernier)
+    call_stack: Vec<Frame>,
+    /// Nombre maximal d'appels imbriqués avant d'arrêter une récursion infinie
+    max_call_depth: usize,
+    /// Nombre maximal de pas (tours de boucle et appels) par exécution
+    step_limit: Option<u64>,
+    /// Durée maximale d'une exécution
+    time_limit: Option<Duration>,
+    /// Pas effectués depuis le début de l'exécution en cours
+    steps: u64,
+    /// Instant (en ms, voir `now_millis`) où l'exécution en cours doit s'arrêter
+    deadline: Option<f64>,
+    /// Entrées et sorties du programme (rubuta, karɓa)
+    host: Box<dyn Host>,
+}
+
+/// Profondeur d'appels par défaut, comparable à celle de Python
+///
+/// Un appel Dabara coûte environ 7 Ko de pile Rust en release (bien plus en debug):
+/// il faut donc environ 8 Mo de pile pour l'atteindre, d'où le fil dédié de `dabara`.
+pub const DEFAULT_MAX_CALL_DEPTH: usize = 1000;
+
+/// Profondeur d'appels du navigateur, dont la pile WebAssembly ne fait que 1 Mo
+///
+/// Avec 6 à 8 Ko par appel (opt-level "z" compris), 1 Mo tient environ 150 appels;
+/// 100 laisse de la marge aux fonctions natives appelées au fond de la récursion.
+pub const BROWSER_MAX_CALL_DEPTH: usize = 100;
+
+/// Invite de `karɓa` quand le programme n'en donne pas
+pub const DEFAULT_INPUT_PROMPT: &str = "Rubuta abu: "; // "Écris quelque chose: "
+
+/// Nombre de pas entre deux lectures de l'horloge quand un délai est fixé
+const CLOCK_CHECK_INTERVAL: u64 = 256;
+
+/// Instant présent en millisecondes, pour le délai d'exécution
+///
+/// `std::time::Instant` n'existe pas dans le navigateur : on y lit l'horloge JavaScript.
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+fn now_millis() -> f64 {
+    js_sys::Date::now()
+}
+
+/// Instant présent en millisecondes, pour le délai d'exécution
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+fn now_millis() -> f64 {
+    use std::sync::OnceLock;
+    use std::time::Instant;
+
+    static ORIGIN: OnceLock<Instant> = OnceLock::new();
+    ORIGIN.get_or_init(Instant::now).elapsed().as_secs_f64() * 1000.0
 }
 
 /// État de contrôle pour les boucles
@@ -88,164 +491,319 @@ enum LoopControl {
     Continue,
 }
 
+impl Default for Interpreter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Interpreter {
     /// Crée un nouveau interpréteur
     pub fn new() -> Self {
         let mut interpreter = Interpreter {
-            scope_stack: vec![HashMap::new()], // Commence avec le scope global
+            globals: HashMap::new(),
+            scope_stack: Vec::new(), // Commence au niveau global
+            frame_base: None,
             functions: HashMap::new(),
+            native_functions: HashMap::new(),
             loop_control: None,
+            call_stack: Vec::new(),
+            max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+            step_limit: None,
+            time_limit: None,
+            steps: 0,
+            deadline: None,
+            host: Box::new(StdHost),
         };
         
         // Register standard library functions
-        // TODO: Initialize stdlib when native function support is complete
-        // stdlib::register_stdlib(&mut interpreter).unwrap_or_else(|e| {
-        //     eprintln!("Warning: Failed to load stdlib: {}", e);
-        // });
+        stdlib::register_stdlib(&mut interpreter).unwrap_or_else(|e| {
+            eprintln!("Warning: Failed to load stdlib: {}", e);
+        });
         
         interpreter
     }
+
+    /// Limite le nombre d'appels imbriqués
+    ///
+    /// Chaque appel Dabara utilise la pile Rust : la limite doit rester assez basse
+    /// pour que la pile du fil d'exécution ne déborde pas avant l'erreur.
+    pub fn set_max_call_depth(&mut self, depth: usize) {
+        self.max_call_depth = depth;
+    }
+
+    /// Nombre maximal d'appels imbriqués
+    pub fn max_call_depth(&self) -> usize {
+        self.max_call_depth
+    }
+
+    /// Remplace les entrées et sorties du programme (le terminal par défaut)
+    pub fn set_host(&mut self, host: Box<dyn Host>) {
+        self.host = host;
+    }
+
+    /// Limite le nombre de pas d'une exécution (`None` : sans limite)
+    ///
+    /// Chaque tour de boucle et chaque appel de fonction compte pour un pas. Le budget
+    /// repart de zéro à chaque appel de `execute`, `execute_interactive` ou
+    /// `execute_with_output`.
+    pub fn set_step_limit(&mut self, steps: Option<u64>) {
+        self.step_limit = steps;
+    }
+
+    /// Limite la durée d'une exécution (`None` : sans limite)
+    ///
+    /// Le délai est vérifié dans les boucles et les appels, comme le budget de pas.
+    pub fn set_time_limit(&mut self, limit: Option<Duration>) {
+        self.time_limit = limit;
+    }
+
+    /// Nombre de pas effectués par la dernière exécution
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    /// Remet à zéro le budget de pas et fixe le délai d'une nouvelle exécution
+    fn begin_run(&mut self) {
+        self.steps = 0;
+        self.deadline = self.time_limit.map(|limit| now_millis() + limit.as_secs_f64() * 1000.0);
+    }
+
+    /// Compte un pas (tour de boucle ou appel) et vérifie le budget et le délai
+    fn tick(&mut self) -> Result<(), Error> {
+        self.steps += 1;
+
+        if let Some(limit) = self.step_limit {
+            if self.steps > limit {
+                return Err(Error::step_limit_exceeded(limit).with_traceback(&self.call_stack));
+            }
+        }
+
+        // Lire l'horloge à chaque pas coûterait cher; un retard de quelques pas est sans importance
+        if let (Some(deadline), Some(limit)) = (self.deadline, self.time_limit) {
+            if self.steps.is_multiple_of(CLOCK_CHECK_INTERVAL) && now_millis() >= deadline {
+                return Err(Error::time_limit_exceeded(limit.as_millis()).with_traceback(&self.call_stack));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Enregistre une fonction native (utilisé par la bibliothèque standard)
+    pub fn register_native(&mut self, name: &str, arity: Arity, func: NativeFn) {
+        self.native_functions.insert(name.to_string(), NativeFunction { arity, func: NativeBody::Simple(func) });
+    }
+
+    /// Enregistre une fonction native qui reçoit des fonctions en argument (map, filter, ...)
+    pub fn register_higher_order(&mut self, name: &str, arity: Arity, func: HigherOrderFn) {
+        self.native_functions.insert(name.to_string(), NativeFunction { arity, func: NativeBody::HigherOrder(func) });
+    }
+
+    /// Enregistre une fonction native qui lit ou écrit par l'hôte (karɓa_lamba, ...)
+    pub fn register_host_native(&mut self, name: &str, arity: Arity, func: HostFn) {
+        self.native_functions.insert(name.to_string(), NativeFunction { arity, func: NativeBody::Host(func) });
+    }
     
     /// Pousse un nouveau scope local
     fn push_scope(&mut self) {
-        self.scope_stack.push(HashMap::new());
+        self.scope_stack.push(Rc::new(RefCell::new(HashMap::new())));
     }
     
     /// Pop le scope courant
     fn pop_scope(&mut self) {
-        if self.scope_stack.len() > 1 {
-            self.scope_stack.pop();
-        }
+        self.scope_stack.pop();
     }
     
     /// Récupère une variable (cherche du scope local vers le global)
     fn get_variable_value(&self, name: &str) -> Option<Value> {
         // Chercher du scope le plus local au global
         for scope in self.scope_stack.iter().rev() {
-            if let Some(value) = scope.get(name) {
+            if let Some(value) = scope.borrow().get(name) {
                 return Some(value.clone());
             }
         }
-        None
+        self.globals.get(name).cloned()
     }
     
     /// Définit une variable dans le scope courant
     pub fn set_variable_value(&mut self, name: String, value: Value) {
-        if let Some(current_scope) = self.scope_stack.last_mut() {
-            current_scope.insert(name, value);
+        match self.scope_stack.last() {
+            Some(current_scope) => {
+                current_scope.borrow_mut().insert(name, value);
+            }
+            None => {
+                self.globals.insert(name, value);
+            }
         }
     }
+
+    /// Déclare une variable avec var / naɗa
+    ///
+    /// Un nom déjà visible dans la fonction en cours (ou parmi les globales, hors
+    /// fonction) est mis à jour, ce qui permet `naɗa x = x + 1` dans une boucle;
+    /// un nouveau nom est créé dans le bloc courant et disparaît à sa sortie.
+    fn declare_variable(&mut self, name: String, value: Value) {
+        let frame_start = self.frame_base.unwrap_or(0);
+        for scope in self.scope_stack[frame_start..].iter().rev() {
+            if let Some(slot) = scope.borrow_mut().get_mut(&name) {
+                *slot = value;
+                return;
+            }
+        }
+
+        if self.frame_base.is_none() {
+            if let Some(slot) = self.globals.get_mut(&name) {
+                *slot = value;
+                return;
+            }
+        }
+
+        self.set_variable_value(name, value);
+    }
+
+    /// Définit une fonction nommée qui capture les scopes où elle est écrite
+    ///
+    /// Au niveau global elle rejoint la table des fonctions; à l'intérieur d'une
+    /// fonction ou d'un bloc, elle est locale comme une variable.
+    fn define_function(&mut self, name: String, parameters: Vec<String>, body: Vec<Statement>) {
+        let function = Function {
+            name: Some(name.clone()),
+            parameters,
+            body,
+            environment: self.scope_stack.clone(),
+        };
+
+        if self.scope_stack.is_empty() {
+            self.functions.insert(name, function);
+        } else {
+            self.set_variable_value(name, Value::Function(Rc::new(function)));
+        }
+    }
+
+    /// Exécute un bloc { ... } dans un scope neuf contenant `bindings`
+    ///
+    /// S'arrête au premier `mayar` (valeur retournée) ou au premier katse / ci_gaba.
+    fn execute_block(&mut self, statements: Vec<Statement>, bindings: Vec<(String, Value)>) -> Result<Option<Value>, Error> {
+        self.push_scope();
+        for (name, value) in bindings {
+            self.set_variable_value(name, value);
+        }
+
+        let mut result = Ok(None);
+        for statement in statements {
+            result = self.execute_statement(statement);
+            if !matches!(result, Ok(None)) || self.loop_control.is_some() {
+                break;
+            }
+        }
+
+        // Le scope disparaît même en cas d'erreur
+        self.pop_scope();
+        result
+    }
     
     /// Exécute un programme complet
     pub fn execute(&mut self, program: Program) -> Result<(), Error> {
+        self.begin_run();
         for statement in program.statements {
-            if let Some(_) = self.execute_statement(statement)? {
-                // Retour au niveau global n'a pas de sens, on l'ignore
-            }
+            // Un retour au niveau global n'a pas de sens, on l'ignore
+            self.execute_statement(statement)?;
         }
         Ok(())
     }
     
+    /// Exécute des statements en mode interactif (REPL)
+    ///
+    /// Retourne la valeur de la dernière instruction si c'est une expression,
+    /// pour que le REPL puisse l'afficher.
+    pub fn execute_interactive(&mut self, program: Program) -> Result<Option<Value>, Error> {
+        self.begin_run();
+        let mut last_value = None;
+
+        for statement in program.statements {
+            last_value = match statement.kind {
+                StatementKind::Expression(expression) => Some(self.evaluate_expression(expression)?),
+                kind => {
+                    self.execute_statement(Statement::new(kind, statement.span))?;
+                    None
+                }
+            };
+        }
+
+        Ok(last_value)
+    }
+    
     /// Exécute un statement et retourne Some(Value) si c'est un return
     fn execute_statement(&mut self, statement: Statement) -> Result<Option<Value>, Error> {
-        match statement {
-            Statement::Let { name, value } => {
+        let span = statement.span;
+
+        match statement.kind {
+            StatementKind::Let { name, value } => {
                 let evaluated_value = self.evaluate_expression(value)?;
-                self.set_variable_value(name, evaluated_value);
+                self.declare_variable(name, evaluated_value);
                 Ok(None)
             }
             
-            Statement::Print(expression) => {
+            StatementKind::Print(expression) => {
                 let value = self.evaluate_expression(expression)?;
-                println!("{}", value);
+                self.host.write(&format!("{}\n", value));
                 Ok(None)
             }
             
-            Statement::FunctionDef { name, parameters, body } => {
-                let function = Function { parameters, body };
-                self.functions.insert(name, function);
+            StatementKind::FunctionDef { name, parameters, body } => {
+                self.define_function(name, parameters, body);
                 Ok(None)
             }
             
-            Statement::If { condition, then_branch, else_branch } => {
+            StatementKind::If { condition, then_branch, else_branch } => {
                 let condition_value = self.evaluate_expression(condition)?;
 
                 // Évaluer la condition comme booléenne
-                let is_true = match condition_value {
-                    Value::Boolean(b) => b,
-                    Value::Number(n) => n != 0,
-                    Value::Float(f) => f != 0.0 && !f.is_nan(),
-                    Value::String(s) => !s.is_empty(),
-                    Value::List(l) => !l.is_empty(),
-                };
+                let is_true = condition_value.is_truthy();
                 
                 if is_true {
-                    // Exécuter la branche then
-                    for statement in then_branch {
-                        if let Some(return_value) = self.execute_statement(statement)? {
-                            return Ok(Some(return_value));
-                        }
-                    }
+                    // Exécuter la branche then dans son propre scope
+                    self.execute_block(then_branch, Vec::new())
                 } else if let Some(else_stmt) = else_branch {
                     // Exécuter la branche else
-                    if let Some(return_value) = self.execute_statement(*else_stmt)? {
-                        return Ok(Some(return_value));
-                    }
+                    self.execute_statement(*else_stmt)
+                } else {
+                    Ok(None)
                 }
-                
-                Ok(None)
             }
             
-            Statement::Return(expression) => {
+            StatementKind::Return(expression) => {
                 let value = self.evaluate_expression(expression)?;
                 Ok(Some(value))
             }
             
-            Statement::Expression(expression) => {
+            StatementKind::Expression(expression) => {
                 // Exécuter l'expression et ignorer le résultat
                 // Utile pour les appels de fonctions standalone
                 self.evaluate_expression(expression)?;
                 Ok(None)
             }
 
-            Statement::While { condition, body } => {
+            StatementKind::While { condition, body } => {
                 loop {
+                    self.tick().map_err(|e| e.with_span(span))?;
+
                     // Reset loop control at start of each iteration
                     self.loop_control = None;
                     
                     let condition_value = self.evaluate_expression(condition.clone())?;
 
                     // Évaluer la condition comme booléenne
-                    let is_true = match condition_value {
-                        Value::Boolean(b) => b,
-                        Value::Number(n) => n != 0,
-                        Value::Float(f) => f != 0.0 && !f.is_nan(),
-                        Value::String(s) => !s.is_empty(),
-                        Value::List(l) => !l.is_empty(),
-                    };
+                    let is_true = condition_value.is_truthy();
 
                     if !is_true {
                         break;
                     }
 
-                    // Exécuter le corps de la boucle
-                    for statement in body.clone() {
-                        if let Some(return_value) = self.execute_statement(statement)? {
-                            return Ok(Some(return_value));
-                        }
-                        
-                        // Handle break/continue
-                        if let Some(control) = &self.loop_control {
-                            match control {
-                                LoopControl::Break => break, // Exit while loop
-                                LoopControl::Continue => break, // Continue to next iteration
-                            }
-                        }
-                    }
-                    
-                    // If continue was called, continue to next iteration
-                    if matches!(self.loop_control, Some(LoopControl::Continue)) {
-                        continue;
+                    // Exécuter le corps de la boucle (un scope neuf à chaque tour)
+                    if let Some(return_value) = self.execute_block(body.clone(), Vec::new())? {
+                        self.loop_control = None;
+                        return Ok(Some(return_value));
                     }
                     
                     // If break was called, exit the loop
@@ -259,46 +817,31 @@ impl Interpreter {
                 Ok(None)
             }
 
-            Statement::For { variable, iterable, body } => {
+            StatementKind::For { index, variable, iterable, body } => {
                 let iterable_value = self.evaluate_expression(iterable)?;
+                let elements = self.iteration_items(iterable_value)
+                    .map_err(|e| e.with_span(span))?;
 
-                match iterable_value {
-                    Value::List(elements) => {
-                        for element in elements {
-                            // Reset loop control at start of each iteration
-                            self.loop_control = None;
-                            
-                            self.set_variable_value(variable.clone(), element);
-
-                            for statement in body.clone() {
-                                if let Some(return_value) = self.execute_statement(statement)? {
-                                    return Ok(Some(return_value));
-                                }
-                                
-                                // Handle break/continue
-                                if let Some(control) = &self.loop_control {
-                                    match control {
-                                        LoopControl::Break => break, // Exit for loop
-                                        LoopControl::Continue => break, // Continue to next iteration
-                                    }
-                                }
-                            }
-                            
-                            // If continue was called, continue to next iteration
-                            if matches!(self.loop_control, Some(LoopControl::Continue)) {
-                                continue;
-                            }
-                            
-                            // If break was called, exit the loop
-                            if matches!(self.loop_control, Some(LoopControl::Break)) {
-                                break;
-                            }
-                        }
+                for (position, element) in elements.enumerate() {
+                    self.tick().map_err(|e| e.with_span(span))?;
+
+                    // Reset loop control at start of each iteration
+                    self.loop_control = None;
+
+                    // Les variables de boucle n'existent que dans le corps
+                    let mut bindings = vec![(variable.clone(), element)];
+                    if let Some(index) = &index {
+                        bindings.push((index.clone(), Value::Number(position as i64)));
                     }
-                    _ => {
-                        return Err(Error::runtime_error(
-                            "Don loop yana bukata jeri (For loop requires a list)"
-                        ));
+
+                    if let Some(return_value) = self.execute_block(body.clone(), bindings)? {
+                        self.loop_control = None;
+                        return Ok(Some(return_value));
+                    }
+                    
+                    // If break was called, exit the loop
+                    if matches!(self.loop_control, Some(LoopControl::Break)) {
+                        break;
                     }
                 }
                 
@@ -307,12 +850,46 @@ impl Interpreter {
                 Ok(None)
             }
 
-            Statement::Break => {
+            StatementKind::Try { body, catch_variable, catch_body, finally_body } => {
+                self.execute_try(body, catch_variable, catch_body, finally_body)
+            }
+
+            StatementKind::Throw(expression) => Err(self.thrown_error(expression, span)),
+
+            StatementKind::Assert { condition, message } => {
+                self.check_assertion(condition, message).map_err(|e| e.with_span(span))?;
+                Ok(None)
+            }
+
+            // Les blocs gwaji ne s'exécutent qu'avec `dabara test`
+            StatementKind::Test { .. } => Ok(None),
+
+            StatementKind::Assign { name, operator, value } => {
+                let value = match operator {
+                    None => self.evaluate_expression(value)?,
+                    Some(compound) => {
+                        let current = self.get_variable_value(&name)
+                            .ok_or_else(|| Error::variable_not_found(&name).with_span(span))?;
+                        let right = self.evaluate_expression(value)?;
+                        self.evaluate_binary_operation(current, compound.operator, right)
+                            .map_err(|e| e.with_span(compound.span))?
+                    }
+                };
+                self.assign_variable(&name, value).map_err(|e| e.with_span(span))?;
+                Ok(None)
+            }
+
+            StatementKind::IndexAssign { object, index, operator, value } => {
+                self.assign_index(object, index, operator, value)?;
+                Ok(None)
+            }
+
+            StatementKind::Break => {
                 self.loop_control = Some(LoopControl::Break);
                 Ok(None)
             }
 
-            Statement::Continue => {
+            StatementKind::Continue => {
                 self.loop_control = Some(LoopControl::Continue);
                 Ok(None)
             }
@@ -320,68 +897,123 @@ impl Interpreter {
     }
     
     /// Évalue une expression
+    ///
+    /// Les erreurs sans position reçoivent celle de l'expression.
     fn evaluate_expression(&mut self, expression: Expression) -> Result<Value, Error> {
+        let span = expression.span;
+        self.evaluate_expression_kind(expression.kind, span)
+            .map_err(|e| e.with_span(span))
+    }
+
+    /// Évalue le contenu d'une expression située à `span` (le site d'appel pour les fonctions)
+    fn evaluate_expression_kind(&mut self, expression: ExpressionKind, span: Span) -> Result<Value, Error> {
         match expression {
-            Expression::Number(n) => Ok(Value::Number(n)),
-            Expression::Float(f) => Ok(Value::Float(f)),
-            Expression::String(s) => Ok(Value::String(s)),
-            Expression::Boolean(b) => Ok(Value::Boolean(b)),
-            Expression::List(elements) => {
+            ExpressionKind::Number(n) => Ok(Value::Number(n)),
+            ExpressionKind::Float(f) => Ok(Value::Float(f)),
+            ExpressionKind::String(s) => Ok(Value::String(s)),
+            ExpressionKind::Boolean(b) => Ok(Value::Boolean(b)),
+            ExpressionKind::List(elements) => {
                 let mut values = Vec::new();
                 for element in elements {
                     let value = self.evaluate_expression(element)?;
                     values.push(value);
                 }
-                Ok(Value::List(values))
+                Ok(Value::list(values))
+            }
+            ExpressionKind::Map(entries) => {
+                let mut map = OrderedMap::new();
+                for (key, value) in entries {
+                    let key_span = key.span;
+                    let key = self.evaluate_expression(key)?;
+                    OrderedMap::check_key(&key).map_err(|e| e.with_span(key_span))?;
+                    let value = self.evaluate_expression(value)?;
+                    map.insert(key, value);
+                }
+                Ok(Value::map(map))
             }
-            Expression::Identifier(name) => {
+            ExpressionKind::Identifier(name) => {
+                // Le nom d'une fonction désigne la fonction elle-même: map(jeri, ninka)
                 self.get_variable_value(&name)
+                    .or_else(|| self.function_value(&name))
                     .ok_or_else(|| Error::variable_not_found(&name))
             }
-            Expression::BinaryOp { left, operator, right } => {
+            ExpressionKind::BinaryOp { left, operator: BinaryOperator::And, right } => {
+                // Court-circuit: la droite n'est évaluée que si la gauche est vraie
+                let left_val = self.evaluate_expression(*left)?;
+                if !left_val.is_truthy() {
+                    return Ok(Value::Boolean(false));
+                }
+                Ok(Value::Boolean(self.evaluate_expression(*right)?.is_truthy()))
+            }
+            ExpressionKind::BinaryOp { left, operator: BinaryOperator::Or, right } => {
+                // Court-circuit: la droite n'est évaluée que si la gauche est fausse
+                let left_val = self.evaluate_expression(*left)?;
+                if left_val.is_truthy() {
+                    return Ok(Value::Boolean(true));
+                }
+                Ok(Value::Boolean(self.evaluate_expression(*right)?.is_truthy()))
+            }
+            ExpressionKind::BinaryOp { left, operator, right } => {
                 let left_val = self.evaluate_expression(*left)?;
                 let right_val = self.evaluate_expression(*right)?;
 
                 self.evaluate_binary_operation(left_val, operator, right_val)
             }
-            Expression::FunctionCall { name, arguments } => {
-                self.call_function(name, arguments)
+            ExpressionKind::FunctionCall { name, arguments } => {
+                self.call_function(name, arguments, span)
             }
-            Expression::Input => {
-                self.get_user_input()
+            ExpressionKind::Call { callee, arguments } => {
+                let callee = self.evaluate_expression(*callee)?;
+                let mut arg_values = Vec::new();
+                for arg in arguments {
+                    arg_values.push(self.evaluate_expression(arg)?);
+                }
+                self.call_value_at(&callee, arg_values, Some(span))
+            }
+            ExpressionKind::Lambda { parameters, body } => {
+                // La fonction anonyme capture les scopes locaux où elle est créée
+                Ok(Value::Function(Rc::new(Function {
+                    name: None,
+                    parameters,
+                    body,
+                    environment: self.scope_stack.clone(),
+                })))
+            }
+            ExpressionKind::Input(prompt) => {
+                let prompt = match prompt {
+                    Some(prompt) => self.evaluate_expression(*prompt)?.to_string(),
+                    None => DEFAULT_INPUT_PROMPT.to_string(),
+                };
+                self.get_user_input(&prompt)
             }
-            Expression::Index { object, index } => {
+            ExpressionKind::Index { object, index } => {
                 let obj_value = self.evaluate_expression(*object)?;
                 let idx_value = self.evaluate_expression(*index)?;
-
-                match (obj_value, idx_value) {
-                    (Value::List(elements), Value::Number(idx)) => {
-                        let index = self.normalize_index(idx, elements.len())?;
-                        elements.get(index)
-                            .cloned()
-                            .ok_or_else(|| Error::runtime_error("Lamba ya wuce iyaka (Index out of bounds)"))
-                    }
-                    (Value::String(s), Value::Number(idx)) => {
-                        let chars: Vec<char> = s.chars().collect();
-                        let index = self.normalize_index(idx, chars.len())?;
-                        chars.get(index)
-                            .map(|c| Value::String(c.to_string()))
-                            .ok_or_else(|| Error::runtime_error("Lamba ya wuce iyaka (Index out of bounds)"))
-                    }
-                    _ => {
-                        Err(Error::runtime_error("Indexing yana bukata jeri ko jimla (Indexing requires a list or string)"))
-                    }
-                }
+                self.index_value(obj_value, idx_value)
             }
-            Expression::MethodCall { receiver, method, arguments } => {
+            ExpressionKind::MethodCall { receiver, method, arguments } => {
                 self.call_method(*receiver, method, arguments)
             }
-            Expression::UnaryOp { operator, operand } => {
+            ExpressionKind::Range { start, end, inclusive, step } => {
+                let start = self.range_bound(*start)?;
+                let end = self.range_bound(*end)?;
+                let step = match step {
+                    Some(step) => self.range_bound(*step)?,
+                    None => 1,
+                };
+                Range::new(start, end, inclusive, step).map(Value::Range)
+            }
+            ExpressionKind::UnaryOp { operator, operand } => {
                 let operand_val = self.evaluate_expression(*operand)?;
                 match (operator, operand_val) {
-                    (UnaryOperator::Negate, Value::Number(n)) => Ok(Value::Number(-n)),
+                    (UnaryOperator::Negate, Value::Number(n)) => {
+                        n.checked_neg().map(Value::Number).ok_or_else(|| Error::integer_overflow("-"))
+                    }
                     (UnaryOperator::Negate, Value::Float(f)) => Ok(Value::Float(-f)),
-                    (UnaryOperator::Not, Value::Boolean(b)) => Ok(Value::Boolean(!b)),
+                    (UnaryOperator::Negate, Value::Decimal(d)) => {
+                        d.checked_neg().map(Value::Decimal).ok_or_else(|| Error::integer_overflow("-"))
+                    }
+                    (UnaryOperator::Not, value) => Ok(Value::Boolean(!value.is_truthy())),
                     _ => Err(Error::runtime_error("Invalid unary operation")),
                 }
             }
@@ -398,25 +1030,58 @@ impl Interpreter {
         match (left, operator, right) {
             // Addition de nombres
             (Value::Number(a), BinaryOperator::Add, Value::Number(b)) => {
-                Ok(Value::Number(a + b))
+                a.checked_add(b).map(Value::Number).ok_or_else(|| Error::integer_overflow("+"))
             }
             
             // Soustraction de nombres
             (Value::Number(a), BinaryOperator::Subtract, Value::Number(b)) => {
-                Ok(Value::Number(a - b))
+                a.checked_sub(b).map(Value::Number).ok_or_else(|| Error::integer_overflow("-"))
             }
             
             // Multiplication de nombres
             (Value::Number(a), BinaryOperator::Multiply, Value::Number(b)) => {
-                Ok(Value::Number(a * b))
+                a.checked_mul(b).map(Value::Number).ok_or_else(|| Error::integer_overflow("*"))
             }
             
             // Division de nombres
             (Value::Number(a), BinaryOperator::Divide, Value::Number(b)) => {
                 if b == 0 {
-                    Err(Error::runtime_error("Ba za a iya raba da sifili ba (Division par zéro)"))
+                    Err(Error::division_by_zero())
                 } else {
-                    Ok(Value::Number(a / b))
+                    a.checked_div(b).map(Value::Number).ok_or_else(|| Error::integer_overflow("/"))
+                }
+            }
+
+            // Reste, division entière et puissance de nombres entiers
+            (Value::Number(_), BinaryOperator::Modulo | BinaryOperator::FloorDivide, Value::Number(0)) => {
+                Err(Error::division_by_zero())
+            }
+            (Value::Number(a), BinaryOperator::Modulo, Value::Number(b)) => {
+                Ok(Value::Number(floor_mod(a, b)))
+            }
+            (Value::Number(a), BinaryOperator::FloorDivide, Value::Number(b)) => {
+                floor_div(a, b).map(Value::Number).ok_or_else(|| Error::integer_overflow("//"))
+            }
+            (Value::Number(a), BinaryOperator::Power, Value::Number(b)) if b >= 0 => {
+                u32::try_from(b).ok()
+                    .and_then(|exp| a.checked_pow(exp))
+                    .map(Value::Number)
+                    .ok_or_else(|| Error::integer_overflow("**"))
+            }
+
+            // Reste, division entière et puissance avec au moins un flottant
+            (
+                left @ (Value::Number(_) | Value::Float(_)),
+                operator @ (BinaryOperator::Modulo | BinaryOperator::FloorDivide | BinaryOperator::Power),
+                right @ (Value::Number(_) | Value::Float(_)),
+            ) => {
+                let a = as_f64(&left);
+                let b = as_f64(&right);
+                match operator {
+                    BinaryOperator::Power => Ok(Value::Float(a.powf(b))),
+                    _ if b == 0.0 => Err(Error::division_by_zero()),
+                    BinaryOperator::Modulo => Ok(Value::Float(a - b * (a / b).floor())),
+                    _ => Ok(Value::Float((a / b).floor())),
                 }
             }
 
@@ -435,21 +1100,21 @@ impl Interpreter {
 
             (Value::Float(a), BinaryOperator::Divide, Value::Float(b)) => {
                 if b == 0.0 {
-                    Err(Error::runtime_error("Ba za a iya raba da sifili ba (Division par zéro)"))
+                    Err(Error::division_by_zero())
                 } else {
                     Ok(Value::Float(a / b))
                 }
             }
             (Value::Number(a), BinaryOperator::Divide, Value::Float(b)) => {
                 if b == 0.0 {
-                    Err(Error::runtime_error("Ba za a iya raba da sifili ba (Division par zéro)"))
+                    Err(Error::division_by_zero())
                 } else {
                     Ok(Value::Float(a as f64 / b))
                 }
             }
             (Value::Float(a), BinaryOperator::Divide, Value::Number(b)) => {
                 if b == 0 {
-                    Err(Error::runtime_error("Ba za a iya raba da sifili ba (Division par zéro)"))
+                    Err(Error::division_by_zero())
                 } else {
                     Ok(Value::Float(a / b as f64))
                 }
@@ -499,11 +1164,24 @@ impl Interpreter {
                 Ok(Value::String(format!("{}{}", bool_str, b)))
             }
             
+            // Concaténation chaîne + kuɗi
+            (Value::String(a), BinaryOperator::Add, Value::Decimal(b)) => {
+                Ok(Value::String(format!("{}{}", a, b)))
+            }
+            (Value::Decimal(a), BinaryOperator::Add, Value::String(b)) => {
+                Ok(Value::String(format!("{}{}", a, b)))
+            }
+            
             // Concaténation explicite
             (left, BinaryOperator::Concat, right) => {
-                Ok(Value::String(format!("{}{}", left.to_string(), right.to_string())))
+                Ok(Value::String(format!("{}{}", left, right)))
             }
             
+            // Kuɗi: calcul exact avec un autre kuɗi ou un entier
+            (Value::Decimal(a), op, Value::Decimal(b)) => decimal_operation(a, op, b),
+            (Value::Decimal(a), op, Value::Number(b)) => decimal_operation(a, op, Decimal::from_int(b)),
+            (Value::Number(a), op, Value::Decimal(b)) => decimal_operation(Decimal::from_int(a), op, b),
+            
             // Opérateurs de comparaison
             (Value::Number(a), BinaryOperator::Equal, Value::Number(b)) => {
                 Ok(Value::Boolean(a == b))
@@ -538,21 +1216,17 @@ impl Interpreter {
                 Ok(Value::Boolean(a >= b))
             }
             
+            // Égalité structurelle des listes, dictionnaires et zangon
+            (left @ (Value::List(_) | Value::Map(_) | Value::Range(_)), BinaryOperator::Equal, right) => {
+                Ok(Value::Boolean(left.equals(&right)))
+            }
+            (left @ (Value::List(_) | Value::Map(_) | Value::Range(_)), BinaryOperator::NotEqual, right) => {
+                Ok(Value::Boolean(!left.equals(&right)))
+            }
+            
             // Opérations invalides
             (left, op, right) => {
-                let op_name = match op {
-                    BinaryOperator::Add => "ƙara",
-                    BinaryOperator::Subtract => "rage",
-                    BinaryOperator::Multiply => "ninka",
-                    BinaryOperator::Divide => "raba",
-                    BinaryOperator::Concat => "+",
-                    BinaryOperator::Equal => "==",
-                    BinaryOperator::NotEqual => "!=",
-                    BinaryOperator::Less => "<",
-                    BinaryOperator::Greater => ">",
-                    BinaryOperator::LessEqual => "<=",
-                    BinaryOperator::GreaterEqual => ">=",
-                };
+                let op_name = operator_name(&op);
                 
                 Err(Error::invalid_operation(
                     op_name,
@@ -565,222 +1239,403 @@ impl Interpreter {
     
     /// Retourne la valeur d'une variable (pour les tests)
     pub fn get_variable(&self, name: &str) -> Option<&Value> {
-        // Cherche dans le scope global (index 0)
-        self.scope_stack.get(0)?.get(name)
+        // Cherche dans le scope global
+        self.globals.get(name)
+    }
+    
+    /// Noms des variables globales, triés par ordre alphabétique
+    pub fn variable_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.globals.keys().cloned().collect();
+        names.sort();
+        names
     }
     
     /// Définit une variable (pour les tests)
     pub fn set_variable(&mut self, name: String, value: Value) {
-        if let Some(global_scope) = self.scope_stack.get_mut(0) {
-            global_scope.insert(name, value);
-        }
+        self.globals.insert(name, value);
     }
     
     /// Efface toutes les variables
     pub fn clear_variables(&mut self) {
+        self.globals.clear();
         self.scope_stack.clear();
-        self.scope_stack.push(HashMap::new()); // Recréer le scope global
+        self.frame_base = None;
         self.functions.clear();
-    }
 
-    /// Exécute un programme avec capture de sortie (pour WASM)
-    pub fn execute_with_output(&mut self, program: Program, output: &mut String) -> Result<(), Error> {
-        for statement in program.statements {
-            if let Some(_) = self.execute_statement_with_output(statement, output)? {
-                // Retour au niveau global
-            }
-        }
-        Ok(())
+        // Les constantes de la stdlib (DABARAN_PI, ...) vivent dans le scope global
+        stdlib::register_stdlib(self).unwrap_or_else(|e| {
+            eprintln!("Warning: Failed to load stdlib: {}", e);
+        });
     }
 
-    /// Exécute un statement avec capture de sortie (pour WASM)
-    fn execute_statement_with_output(&mut self, statement: Statement, output: &mut String) -> Result<Option<Value>, Error> {
-        match statement {
-            Statement::Print(expression) => {
-                let value = self.evaluate_expression(expression)?;
-                output.push_str(&format!("{}\n", value));
-                Ok(None)
-            }
+    /// Exécute un programme en ajoutant sa sortie à `output` au lieu de l'écrire
+    ///
+    /// Le programme n'a pas d'entrée à lire; le host habituel est rétabli ensuite.
+    pub fn execute_with_output(&mut self, program: Program, output: &mut String) -> Result<(), Error> {
+        let buffer = BufferHost::new();
+        let host = std::mem::replace(&mut self.host, Box::new(buffer.clone()));
 
-            Statement::Let { name, value } => {
-                let evaluated_value = self.evaluate_expression(value)?;
-                self.set_variable_value(name, evaluated_value);
-                Ok(None)
-            }
+        let result = self.execute(program);
 
-            Statement::FunctionDef { name, parameters, body } => {
-                let function = Function { parameters, body };
-                self.functions.insert(name, function);
-                Ok(None)
-            }
+        self.host = host;
+        output.push_str(&buffer.output());
+        result
+    }
 
-            Statement::If { condition, then_branch, else_branch } => {
-                let condition_value = self.evaluate_expression(condition)?;
-                let is_true = match condition_value {
-                    Value::Boolean(b) => b,
-                    Value::Number(n) => n != 0,
-                    Value::Float(f) => f != 0.0 && !f.is_nan(),
-                    Value::String(s) => !s.is_empty(),
-                    Value::List(l) => !l.is_empty(),
-                };
+    /// Exécute gwada / kama / a_ƙarshe, chaque bloc dans un scope neuf
+    ///
+    /// Une erreur d'exécution du bloc gwada est confiée au bloc kama. Le bloc a_ƙarshe
+    /// s'exécute toujours; s'il retourne ou échoue lui-même, c'est son résultat qui compte.
+    fn execute_try(
+        &mut self,
+        body: Vec<Statement>,
+        catch_variable: Option<String>,
+        catch_body: Option<Vec<Statement>>,
+        finally_body: Option<Vec<Statement>>,
+    ) -> Result<Option<Value>, Error> {
+        let mut result = self.execute_block(body, Vec::new());
 
-                if is_true {
-                    for statement in then_branch {
-                        if let Some(return_value) = self.execute_statement_with_output(statement, output)? {
-                            return Ok(Some(return_value));
-                        }
-                    }
-                } else if let Some(else_stmt) = else_branch {
-                    if let Some(return_value) = self.execute_statement_with_output(*else_stmt, output)? {
-                        return Ok(Some(return_value));
-                    }
+        if let Some(catch_body) = catch_body {
+            if let Err(error) = &result {
+                if error.is_catchable() {
+                    let bindings = catch_variable
+                        .map(|name| vec![(name, Value::Error(Rc::new(error.clone())))])
+                        .unwrap_or_default();
+                    result = self.execute_block(catch_body, bindings);
                 }
-                Ok(None)
             }
+        }
 
-            Statement::Return(expression) => {
-                let value = self.evaluate_expression(expression)?;
-                Ok(Some(value))
+        if let Some(finally_body) = finally_body {
+            // Un katse / ci_gaba venu du bloc gwada doit survivre au bloc a_ƙarshe
+            let pending_control = self.loop_control.take();
+            match self.execute_block(finally_body, Vec::new()) {
+                Ok(None) if self.loop_control.is_none() => self.loop_control = pending_control,
+                Ok(None) => {}
+                other => return other,
             }
+        }
 
-            Statement::Expression(expression) => {
-                self.evaluate_expression(expression)?;
-                Ok(None)
-            }
+        result
+    }
 
-            Statement::While { condition, body } => {
-                loop {
-                    self.loop_control = None;
-                    let condition_value = self.evaluate_expression(condition.clone())?;
-                    let is_true = match condition_value {
-                        Value::Boolean(b) => b,
-                        Value::Number(n) => n != 0,
-                        Value::Float(f) => f != 0.0 && !f.is_nan(),
-                        Value::String(s) => !s.is_empty(),
-                        Value::List(l) => !l.is_empty(),
-                    };
-
-                    if !is_true { break; }
-
-                    for statement in body.clone() {
-                        if let Some(return_value) = self.execute_statement_with_output(statement, output)? {
-                            return Ok(Some(return_value));
-                        }
-                        if self.loop_control.is_some() { break; }
-                    }
+    /// Erreur levée par `jefa`: une erreur rattrapée est relancée telle quelle
+    fn thrown_error(&mut self, expression: Expression, span: Span) -> Error {
+        match self.evaluate_expression(expression) {
+            Ok(Value::Error(error)) => (*error).clone(),
+            Ok(value) => Error::thrown(&value.to_string()).with_span(span),
+            Err(error) => error,
+        }
+    }
 
-                    if matches!(self.loop_control, Some(LoopControl::Continue)) { continue; }
-                    if matches!(self.loop_control, Some(LoopControl::Break)) { break; }
+    /// Vérifie une assertion `tabbatar`
+    ///
+    /// Pour une comparaison, le message d'échec montre les deux valeurs comparées.
+    fn check_assertion(&mut self, condition: Expression, message: Option<Expression>) -> Result<(), Error> {
+        let comparison = match condition.kind {
+            ExpressionKind::BinaryOp { left, operator, right } if is_comparison(&operator) => {
+                let left = self.evaluate_expression(*left)?;
+                let right = self.evaluate_expression(*right)?;
+                let shown = format!(
+                    "{} {} {}",
+                    assertion_operand(&left),
+                    operator_name(&operator),
+                    assertion_operand(&right)
+                );
+                if self.evaluate_binary_operation(left, operator, right)?.is_truthy() {
+                    return Ok(());
                 }
-                self.loop_control = None;
-                Ok(None)
+                Some(shown)
             }
-
-            Statement::For { variable, iterable, body } => {
-                let iterable_value = self.evaluate_expression(iterable)?;
-                match iterable_value {
-                    Value::List(elements) => {
-                        for element in elements {
-                            self.loop_control = None;
-                            self.set_variable_value(variable.clone(), element);
-                            for statement in body.clone() {
-                                if let Some(return_value) = self.execute_statement_with_output(statement, output)? {
-                                    return Ok(Some(return_value));
-                                }
-                                if self.loop_control.is_some() { break; }
-                            }
-                            if matches!(self.loop_control, Some(LoopControl::Continue)) { continue; }
-                            if matches!(self.loop_control, Some(LoopControl::Break)) { break; }
-                        }
-                    }
-                    _ => {
-                        return Err(Error::runtime_error(
-                            "Don loop yana bukata jeri (For loop requires a list)"
-                        ));
-                    }
+            kind => {
+                if self.evaluate_expression(Expression::new(kind, condition.span))?.is_truthy() {
+                    return Ok(());
                 }
-                self.loop_control = None;
-                Ok(None)
+                None
             }
+        };
 
-            Statement::Break => {
-                self.loop_control = Some(LoopControl::Break);
-                Ok(None)
-            }
+        let message = match message {
+            Some(message) => Some(self.evaluate_expression(message)?.to_string()),
+            None => None,
+        };
+        let detail = match (message, comparison) {
+            (Some(message), Some(comparison)) => Some(format!("{}: {}", message, comparison)),
+            (message, comparison) => message.or(comparison),
+        };
+        Err(Error::assertion_failed(detail.as_deref()))
+    }
 
-            Statement::Continue => {
-                self.loop_control = Some(LoopControl::Continue);
-                Ok(None)
-            }
+    /// Appelle une fonction par son nom: variable contenant une fonction, native ou définie par l'utilisateur
+    fn call_function(&mut self, name: String, arguments: Vec<Expression>, span: Span) -> Result<Value, Error> {
+        let callee = self.callable_named(&name)?;
+
+        // Vérifier le nombre d'arguments avant de les évaluer
+        check_arity(&callee, arguments.len())?;
+
+        let mut arg_values = Vec::new();
+        for arg in arguments {
+            arg_values.push(self.evaluate_expression(arg)?);
         }
+
+        self.call_value_at(&callee, arg_values, Some(span))
     }
-    
-    /// Appelle une fonction définie par l'utilisateur
-    fn call_function(&mut self, name: String, arguments: Vec<Expression>) -> Result<Value, Error> {
-        // Récupérer la définition de la fonction
-        let function = self.functions.get(&name)
-            .cloned()
-            .ok_or_else(|| Error::runtime_error(&format!("Fonction '{}' ba a gani ba (fonction non trouvée)", name)))?;
-        
-        // Vérifier le nombre d'arguments
-        if arguments.len() != function.parameters.len() {
-            return Err(Error::runtime_error(&format!(
-                "Fonction '{}' tana bukata {} argument(s), amma {} an bayar (fonction '{}' attend {} argument(s), mais {} fourni(s))",
-                name, function.parameters.len(), arguments.len(), name, function.parameters.len(), arguments.len()
-            )));
+
+    /// Retrouve ce qu'un appel `nom(...)` désigne
+    ///
+    /// Une variable contenant une fonction passe en premier, puis les fonctions natives
+    /// (prioritaires sur les fonctions de l'utilisateur).
+    fn callable_named(&self, name: &str) -> Result<Value, Error> {
+        let variable = self.get_variable_value(name);
+        if let Some(value @ (Value::Function(_) | Value::NativeFunction { .. })) = variable {
+            return Ok(value);
         }
-        
-        // Évaluer les arguments
-        let mut arg_values = Vec::new();
-        for arg in arguments {
-            let value = self.evaluate_expression(arg)?;
-            arg_values.push(value);
+
+        match (self.function_value(name), variable) {
+            (Some(function), _) => Ok(function),
+            (None, Some(other)) => Err(Error::runtime_error(&format!(
+                "'{}' ba aiki ba ne, {} ne (not a function)",
+                name,
+                other.type_name()
+            ))),
+            (None, None) => Err(Error::function_not_found(name)),
         }
-        
-        // Créer un nouveau scope local
-        self.push_scope();
-        
+    }
+
+    /// Fonction native ou définie par l'utilisateur sous forme de valeur
+    fn function_value(&self, name: &str) -> Option<Value> {
+        if let Some(native) = self.native_functions.get(name) {
+            return Some(Value::NativeFunction { name: name.to_string(), function: native.clone() });
+        }
+        self.functions.get(name).map(|function| Value::Function(Rc::new(function.clone())))
+    }
+
+    /// Appelle une valeur de fonction avec des arguments déjà évalués
+    ///
+    /// Utilisé par les appels `f(x)` et par les fonctions natives comme `map` ou `filter`.
+    pub fn call_value(&mut self, callee: &Value, arguments: Vec<Value>) -> Result<Value, Error> {
+        self.call_value_at(callee, arguments, None)
+    }
+
+    /// Appelle une valeur de fonction en l'inscrivant dans la pile des appels
+    ///
+    /// Les fonctions de l'utilisateur et les natives qui rappellent du code Dabara
+    /// (map, filter, ...) ont leur cadre; les autres natives n'en ont pas besoin,
+    /// leur erreur pointe déjà sur l'appel. Une erreur qui remonte emporte une
+    /// copie de la pile au moment où elle s'est produite.
+    fn call_value_at(&mut self, callee: &Value, arguments: Vec<Value>, call_site: Option<Span>) -> Result<Value, Error> {
+        check_arity(callee, arguments.len())?;
+        self.tick()?;
+
+        let function = match callee {
+            Value::Function(function) => function.display_name().to_string(),
+            Value::NativeFunction { name, function: NativeFunction { func: NativeBody::HigherOrder(_), .. } } => name.clone(),
+            _ => return self.invoke(callee, arguments),
+        };
+
+        if self.call_stack.len() >= self.max_call_depth {
+            return Err(Error::recursion_too_deep(&function, self.max_call_depth)
+                .with_traceback(&self.call_stack));
+        }
+
+        self.call_stack.push(Frame { function, call_site });
+        let result = self.invoke(callee, arguments)
+            .map_err(|e| e.with_traceback(&self.call_stack));
+        self.call_stack.pop();
+
+        result
+    }
+
+    /// Exécute une valeur de fonction
+    fn invoke(&mut self, callee: &Value, arguments: Vec<Value>) -> Result<Value, Error> {
+        match callee {
+            Value::Function(function) => self.call_user_function(function, arguments),
+            Value::NativeFunction { function, .. } => match function.func {
+                NativeBody::Simple(func) => func(&arguments),
+                NativeBody::HigherOrder(func) => func(self, &arguments),
+                NativeBody::Host(func) => func(self, &arguments),
+            },
+            other => Err(Error::runtime_error(&format!(
+                "Ba za a iya kiran {} kamar aiki ba (value is not a function)",
+                other.type_name()
+            ))),
+        }
+    }
+
+    /// Exécute le corps d'une fonction de l'utilisateur dans un nouveau scope local
+    ///
+    /// La fonction voit les scopes capturés à sa définition, jamais ceux de l'appelant.
+    fn call_user_function(&mut self, function: &Function, arguments: Vec<Value>) -> Result<Value, Error> {
+        let caller_scopes = std::mem::replace(&mut self.scope_stack, function.environment.clone());
+        let caller_frame = self.frame_base.replace(self.scope_stack.len());
+        let caller_loop_control = self.loop_control.take();
+
         // Lier les paramètres aux valeurs des arguments
-        for (param, arg_value) in function.parameters.iter().zip(arg_values.iter()) {
-            self.set_variable_value(param.clone(), arg_value.clone());
+        let bindings = function.parameters.iter().cloned().zip(arguments).collect();
+        let result = self.execute_block(function.body.clone(), bindings);
+
+        // Restaurer l'environnement de l'appelant, même en cas d'erreur
+        self.scope_stack = caller_scopes;
+        self.frame_base = caller_frame;
+        self.loop_control = caller_loop_control;
+
+        // Sans mayar, une fonction retourne 0
+        Ok(result?.unwrap_or(Value::Number(0)))
+    }
+    
+    /// Affiche une invite et lit une ligne (pour karɓa et ses variantes typées)
+    ///
+    /// La fin de l'entrée est une erreur d'exécution que `kama` peut rattraper.
+    pub fn read_input(&mut self, prompt: &str) -> Result<String, Error> {
+        self.host.prompt(prompt);
+        self.host.read_line()?.ok_or_else(Error::end_of_input)
+    }
+
+    /// Écrit du texte sur la sortie du programme, sans retour à la ligne
+    pub fn write_output(&mut self, text: &str) {
+        self.host.write(text);
+    }
+
+    /// Récupère l'entrée utilisateur en devinant son type (lambar, puis jimla)
+    fn get_user_input(&mut self, prompt: &str) -> Result<Value, Error> {
+        let input = self.read_input(prompt)?;
+        let trimmed = input.trim();
+
+        // Essayer de parser comme nombre d'abord
+        if let Ok(num) = trimmed.parse::<i64>() {
+            Ok(Value::Number(num))
+        } else if let Ok(float_num) = trimmed.parse::<f64>() {
+            Ok(Value::Float(float_num))
+        } else {
+            Ok(Value::String(trimmed.to_string()))
         }
-        
-        // Exécuter le corps de la fonction
-        let mut return_value = Value::Number(0); // Valeur par défaut
-        for statement in function.body {
-            if let Some(value) = self.execute_statement(statement)? {
-                // Un return a été exécuté
-                return_value = value;
-                break;
+    }
+
+    /// Éléments parcourus par une boucle ga: ceux d'une liste, d'un zango, les lettres
+    /// d'une jimla ou les clés d'un dictionnaire
+    ///
+    /// On parcourt une copie pour que le corps de la boucle puisse modifier la collection;
+    /// un zango est parcouru à la demande, sans construire de liste.
+    fn iteration_items(&self, value: Value) -> Result<Box<dyn Iterator<Item = Value>>, Error> {
+        match value {
+            Value::List(elements) => Ok(Box::new(elements.borrow().clone().into_iter())),
+            Value::Map(map) => Ok(Box::new(map.borrow().keys().cloned().collect::<Vec<_>>().into_iter())),
+            Value::Range(range) => Ok(Box::new(range.iter().map(Value::Number))),
+            Value::String(text) => Ok(Box::new(graphemes(&text).into_iter().map(Value::String))),
+            other => Err(Error::runtime_error(&format!(
+                "Ba za a iya yin ga cikin {} ba: ana bukata jeri, zango, jimla ko ƙamus (For loop requires a list, a range, a string or a map)",
+                other.type_name()
+            ))),
+        }
+    }
+
+    /// Évalue une borne ou le pas d'un zango (doit être un entier)
+    fn range_bound(&mut self, expression: Expression) -> Result<i64, Error> {
+        let span = expression.span;
+        match self.evaluate_expression(expression)? {
+            Value::Number(n) => Ok(n),
+            other => Err(Error::runtime_error(&format!(
+                "Zango yana bukata lambobi cikakku, ba {} ba (range bounds must be integers)",
+                other.type_name()
+            )).with_span(span)),
+        }
+    }
+
+    /// Met à jour la variable existante la plus proche (du scope local vers le global)
+    fn assign_variable(&mut self, name: &str, value: Value) -> Result<(), Error> {
+        for scope in self.scope_stack.iter().rev() {
+            if let Some(slot) = scope.borrow_mut().get_mut(name) {
+                *slot = value;
+                return Ok(());
             }
         }
-        
-        // Nettoyer le scope local
-        self.pop_scope();
-        
-        Ok(return_value)
+        match self.globals.get_mut(name) {
+            Some(slot) => {
+                *slot = value;
+                Ok(())
+            }
+            None => Err(Error::variable_not_found(name)),
+        }
     }
-    
-    /// Récupère l'entrée utilisateur
-    fn get_user_input(&mut self) -> Result<Value, Error> {
-        print!("Rubuta abu: "); // "Écris quelque chose: "
-        io::stdout().flush().unwrap();
-
-        let mut input = String::new();
-        match io::stdin().read_line(&mut input) {
-            Ok(_) => {
-                let trimmed = input.trim();
-
-                // Essayer de parser comme nombre d'abord
-                if let Ok(num) = trimmed.parse::<i64>() {
-                    Ok(Value::Number(num))
-                } else if let Ok(float_num) = trimmed.parse::<f64>() {
-                    Ok(Value::Float(float_num))
-                } else {
-                    Ok(Value::String(trimmed.to_string()))
-                }
+
+    /// Affecte une valeur par index ou par clé: jeri[i] = v, m["clé"] = v, grid[i][j] = v
+    ///
+    /// Les listes et dictionnaires étant partagés, on modifie directement le conteneur.
+    ///
+    /// Le conteneur, l'index et la valeur sont évalués une seule fois, dans cet ordre.
+    /// Pour `+=` et ses variantes, l'élément est lu juste avant d'évaluer la valeur.
+    fn assign_index(
+        &mut self,
+        object: Expression,
+        index: Expression,
+        operator: Option<CompoundOperator>,
+        value: Expression,
+    ) -> Result<(), Error> {
+        let span = index.span;
+        let container = self.evaluate_expression(object)?;
+        let key = self.evaluate_expression(index)?;
+        let value = match operator {
+            None => self.evaluate_expression(value)?,
+            Some(compound) => {
+                let current = self.index_value(container.clone(), key.clone())
+                    .map_err(|e| e.with_span(span))?;
+                let right = self.evaluate_expression(value)?;
+                self.evaluate_binary_operation(current, compound.operator, right)
+                    .map_err(|e| e.with_span(compound.span))?
+            }
+        };
+
+        match (container, key) {
+            (Value::List(elements), Value::Number(idx)) => {
+                let len = elements.borrow().len();
+                let position = self.normalize_index(idx, len).map_err(|e| e.with_span(span))?;
+                elements.borrow_mut()[position] = value;
+                Ok(())
+            }
+            (Value::List(_), other) => Err(Error::runtime_error(&format!(
+                "Lambar jeri dole ta zama lambar, ba {} ba (list index must be a number)",
+                other.type_name()
+            )).with_span(span)),
+            (Value::Map(map), key) => {
+                OrderedMap::check_key(&key).map_err(|e| e.with_span(span))?;
+                map.borrow_mut().insert(key, value);
+                Ok(())
+            }
+            (other, _) => Err(Error::runtime_error(&format!(
+                "Ba za a iya sanya ƙima ta index a cikin {} ba (index assignment requires a list or a map)",
+                other.type_name()
+            )).with_span(span)),
+        }
+    }
+
+    /// Lit `conteneur[clé]` dans une liste, un zango, une jimla ou un ƙamus
+    fn index_value(&self, container: Value, key: Value) -> Result<Value, Error> {
+        match (container, key) {
+            (Value::List(elements), Value::Number(idx)) => {
+                let elements = elements.borrow();
+                let index = self.normalize_index(idx, elements.len())?;
+                Ok(elements[index].clone())
+            }
+            (Value::Range(range), Value::Number(idx)) => {
+                range.get(idx)
+                    .map(Value::Number)
+                    .ok_or_else(|| Error::index_out_of_bounds(idx, range.len().min(u64::MAX as i128) as u64))
+            }
+            (Value::String(s), Value::Number(idx)) => {
+                let mut letters = graphemes(&s);
+                let index = self.normalize_index(idx, letters.len())?;
+                Ok(Value::String(letters.swap_remove(index)))
+            }
+            (Value::Map(map), key) => {
+                map.borrow().get(&key)
+                    .cloned()
+                    .ok_or_else(|| Error::runtime_error(&format!(
+                        "Maɓalli '{}' ba ya cikin ƙamus (Key not found)", key
+                    )))
+            }
+            _ => {
+                Err(Error::runtime_error("Indexing yana bukata jeri, zango, jimla ko ƙamus (Indexing requires a list, range, string or map)"))
             }
-            Err(_) => Err(Error::runtime_error("Ba za a iya karba shigarwa ba (Impossible de lire l'entrée)"))
         }
     }
 
@@ -789,12 +1644,12 @@ impl Interpreter {
         if idx < 0 {
             let positive = (len as i64) + idx;
             if positive < 0 {
-                return Err(Error::runtime_error("Lamba ya wuce iyaka (Index out of bounds)"));
+                return Err(Error::index_out_of_bounds(idx, len as u64));
             }
             Ok(positive as usize)
         } else {
             if idx as usize >= len {
-                return Err(Error::runtime_error("Lamba ya wuce iyaka (Index out of bounds)"));
+                return Err(Error::index_out_of_bounds(idx, len as u64));
             }
             Ok(idx as usize)
         }
@@ -807,7 +1662,7 @@ impl Interpreter {
         match (receiver_value, method.as_str()) {
             // String methods
             (Value::String(s), "tsawo") => {
-                Ok(Value::Number(s.chars().count() as i64))
+                Ok(Value::Number(graphemes(&s).len() as i64))
             }
             (Value::String(s), "babba") => {
                 Ok(Value::String(s.to_uppercase()))
@@ -824,13 +1679,12 @@ impl Interpreter {
 
                 match (start_val, end_val) {
                     (Value::Number(start), Value::Number(end)) => {
-                        let chars: Vec<char> = s.chars().collect();
+                        let letters = graphemes(&s);
                         let start_idx = start.max(0) as usize;
-                        let end_idx = (end.max(0) as usize).min(chars.len());
+                        let end_idx = (end.max(0) as usize).min(letters.len());
 
-                        if start_idx <= end_idx && end_idx <= chars.len() {
-                            let substring: String = chars[start_idx..end_idx].iter().collect();
-                            Ok(Value::String(substring))
+                        if start_idx <= end_idx && end_idx <= letters.len() {
+                            Ok(Value::String(letters[start_idx..end_idx].concat()))
                         } else {
                             Err(Error::runtime_error("Yanki ya wuce iyaka (Substring out of bounds)"))
                         }
@@ -849,27 +1703,54 @@ impl Interpreter {
                         let parts: Vec<Value> = s.split(&sep)
                             .map(|p| Value::String(p.to_string()))
                             .collect();
-                        Ok(Value::List(parts))
+                        Ok(Value::list(parts))
                     }
                     _ => Err(Error::runtime_error("raba yana bukata jimla (raba requires a string)"))
                 }
             }
 
-            // List methods
+            // List methods (la liste est partagée: les modifications sont visibles partout)
             (Value::List(elements), "tsawo") => {
-                Ok(Value::Number(elements.len() as i64))
+                Ok(Value::Number(elements.borrow().len() as i64))
             }
-            (Value::List(mut elements), "ƙara") | (Value::List(mut elements), "kara") => {
+            (Value::List(elements), "ƙara") | (Value::List(elements), "kara") => {
                 if arguments.len() != 1 {
                     return Err(Error::runtime_error("ƙara yana bukata argument 1 (ƙara requires 1 argument)"));
                 }
                 let item = self.evaluate_expression(arguments[0].clone())?;
-                elements.push(item);
+                elements.borrow_mut().push(item);
                 Ok(Value::List(elements))
             }
-            (Value::List(mut elements), "cire") => {
-                elements.pop()
-                    .ok_or_else(|| Error::runtime_error("Ba za a iya cire daga jerin komai ba (Cannot pop from empty list)"))
+            (Value::List(elements), "saka") => {
+                if arguments.len() != 2 {
+                    return Err(Error::runtime_error("saka yana bukata arguments 2 (saka requires 2 arguments)"));
+                }
+                let position = self.evaluate_expression(arguments[0].clone())?;
+                let item = self.evaluate_expression(arguments[1].clone())?;
+                let position = match position {
+                    Value::Number(n) => n,
+                    _ => return Err(Error::runtime_error("saka yana bukata lambar (saka requires a number)")),
+                };
+                let len = elements.borrow().len();
+                // On peut insérer juste après le dernier élément
+                let index = if position == len as i64 { len } else { self.normalize_index(position, len)? };
+                elements.borrow_mut().insert(index, item);
+                Ok(Value::List(elements))
+            }
+            (Value::List(elements), "cire") => {
+                match arguments.len() {
+                    0 => elements.borrow_mut().pop()
+                        .ok_or_else(|| Error::runtime_error("Ba za a iya cire daga jerin komai ba (Cannot pop from empty list)")),
+                    1 => {
+                        let position = match self.evaluate_expression(arguments[0].clone())? {
+                            Value::Number(n) => n,
+                            _ => return Err(Error::runtime_error("cire yana bukata lambar (cire requires a number)")),
+                        };
+                        let index = self.normalize_index(position, elements.borrow().len())?;
+                        Ok(elements.borrow_mut().remove(index))
+                    }
+                    _ => Err(Error::runtime_error("cire yana bukata argument 0 ko 1 (cire requires 0 or 1 argument)")),
+                }
             }
             (Value::List(elements), "haɗa") | (Value::List(elements), "hada") => {
                 if arguments.len() != 1 {
@@ -879,7 +1760,7 @@ impl Interpreter {
 
                 match sep_val {
                     Value::String(sep) => {
-                        let joined = elements.iter()
+                        let joined = elements.borrow().iter()
                             .map(|v| v.to_string())
                             .collect::<Vec<_>>()
                             .join(&sep);
@@ -889,7 +1770,246 @@ impl Interpreter {
                 }
             }
 
+            // Range methods
+            (Value::Range(range), "tsawo") => {
+                i64::try_from(range.len())
+                    .map(Value::Number)
+                    .map_err(|_| Error::integer_overflow("tsawo"))
+            }
+            (Value::Range(range), "yana_da") => {
+                if arguments.len() != 1 {
+                    return Err(Error::runtime_error("yana_da yana bukata argument 1 (yana_da requires 1 argument)"));
+                }
+                match self.evaluate_expression(arguments[0].clone())? {
+                    Value::Number(n) => Ok(Value::Boolean(range.contains(n))),
+                    _ => Ok(Value::Boolean(false)),
+                }
+            }
+
+            // Error methods
+            (Value::Error(error), "iri") => Ok(Value::String(error.kind().to_string())),
+            (Value::Error(error), "saƙo") | (Value::Error(error), "sako") => {
+                Ok(Value::String(error.message().to_string()))
+            }
+            (Value::Error(error), "layi") => {
+                Ok(Value::Number(error.span().map_or(0, |span| span.line as i64)))
+            }
+            (Value::Error(error), "ginshiƙi") | (Value::Error(error), "ginshiki") => {
+                Ok(Value::Number(error.span().map_or(0, |span| span.column as i64)))
+            }
+
+            // Map methods
+            (Value::Map(map), "tsawo") => {
+                Ok(Value::Number(map.borrow().len() as i64))
+            }
+            (Value::Map(map), "maɓallai") | (Value::Map(map), "maballai") => {
+                Ok(Value::list(map.borrow().keys().cloned().collect()))
+            }
+            (Value::Map(map), "ƙimomi") | (Value::Map(map), "kimomi") => {
+                Ok(Value::list(map.borrow().values().cloned().collect()))
+            }
+            (Value::Map(map), "yana_da") => {
+                if arguments.len() != 1 {
+                    return Err(Error::runtime_error("yana_da yana bukata argument 1 (yana_da requires 1 argument)"));
+                }
+                let key = self.evaluate_expression(arguments[0].clone())?;
+                let found = map.borrow().contains_key(&key);
+                Ok(Value::Boolean(found))
+            }
+            (Value::Map(map), "saka") => {
+                if arguments.len() != 2 {
+                    return Err(Error::runtime_error("saka yana bukata arguments 2 (saka requires 2 arguments)"));
+                }
+                let key = self.evaluate_expression(arguments[0].clone())?;
+                let value = self.evaluate_expression(arguments[1].clone())?;
+                OrderedMap::check_key(&key)?;
+                map.borrow_mut().insert(key, value);
+                Ok(Value::Map(map))
+            }
+            (Value::Map(map), "cire") => {
+                if arguments.len() != 1 {
+                    return Err(Error::runtime_error("cire yana bukata argument 1 (cire requires 1 argument)"));
+                }
+                let key = self.evaluate_expression(arguments[0].clone())?;
+                let removed = map.borrow_mut().remove(&key);
+                removed.ok_or_else(|| Error::runtime_error(&format!(
+                    "Maɓalli '{}' ba ya cikin ƙamus (Key not found)", key
+                )))
+            }
+
             _ => Err(Error::runtime_error(&format!("Method '{}' ba a gani ba (Method not found)", method)))
         }
     }
-}
\ No newline at end of file
+}
+
+/// Nom d'un opérateur dans les messages d'erreur
+fn operator_name(operator: &BinaryOperator) -> &'static str {
+    match operator {
+        BinaryOperator::Add => "ƙara",
+        BinaryOperator::Subtract => "rage",
+        BinaryOperator::Multiply => "ninka",
+        BinaryOperator::Divide => "raba",
+        BinaryOperator::Modulo => "%",
+        BinaryOperator::FloorDivide => "//",
+        BinaryOperator::Power => "**",
+        BinaryOperator::Concat => "+",
+        BinaryOperator::Equal => "==",
+        BinaryOperator::NotEqual => "!=",
+        BinaryOperator::Less => "<",
+        BinaryOperator::Greater => ">",
+        BinaryOperator::LessEqual => "<=",
+        BinaryOperator::GreaterEqual => ">=",
+        BinaryOperator::And => "da",
+        BinaryOperator::Or => "ko",
+    }
+}
+
+/// Vérifie si l'opérateur compare deux valeurs (==, !=, <, >, <=, >=)
+fn is_comparison(operator: &BinaryOperator) -> bool {
+    matches!(operator,
+        BinaryOperator::Equal |
+        BinaryOperator::NotEqual |
+        BinaryOperator::Less |
+        BinaryOperator::Greater |
+        BinaryOperator::LessEqual |
+        BinaryOperator::GreaterEqual
+    )
+}
+
+/// Valeur comparée dans un échec de `tabbatar`; les jimloli gardent leurs guillemets
+fn assertion_operand(value: &Value) -> String {
+    match value {
+        Value::String(text) => format!("{:?}", text),
+        other => other.to_string(),
+    }
+}
+
+/// Opération entre deux kuɗi
+///
+/// `/` arrondit à au moins DEFAULT_DIVISION_SCALE décimales (mode « rabi »);
+/// `raba_kudi` permet de choisir les décimales et le mode d'arrondi.
+fn decimal_operation(a: Decimal, operator: BinaryOperator, b: Decimal) -> Result<Value, Error> {
+    let overflow = || Error::integer_overflow(operator_name(&operator));
+    match operator {
+        BinaryOperator::Add => a.checked_add(&b).map(Value::Decimal).ok_or_else(overflow),
+        BinaryOperator::Subtract => a.checked_sub(&b).map(Value::Decimal).ok_or_else(overflow),
+        BinaryOperator::Multiply => a.checked_mul(&b).map(Value::Decimal),
+        BinaryOperator::Divide => {
+            let scale = a.scale().max(b.scale()).max(DEFAULT_DIVISION_SCALE);
+            a.checked_div(&b, scale, RoundingMode::HalfUp).map(Value::Decimal)
+        }
+        BinaryOperator::Equal => Ok(Value::Boolean(a == b)),
+        BinaryOperator::NotEqual => Ok(Value::Boolean(a != b)),
+        BinaryOperator::Less => Ok(Value::Boolean(a < b)),
+        BinaryOperator::Greater => Ok(Value::Boolean(a > b)),
+        BinaryOperator::LessEqual => Ok(Value::Boolean(a <= b)),
+        BinaryOperator::GreaterEqual => Ok(Value::Boolean(a >= b)),
+        other => Err(Error::invalid_operation(operator_name(&other), "kuɗi", "kuɗi")),
+    }
+}
+
+/// Division entière arrondie vers le bas: -7 // 2 = -4
+///
+/// Retourne None si le résultat dépasse i64 (i64::MIN // -1).
+fn floor_div(a: i64, b: i64) -> Option<i64> {
+    let quotient = a.checked_div(b)?;
+    if a % b != 0 && (a < 0) != (b < 0) {
+        Some(quotient - 1)
+    } else {
+        Some(quotient)
+    }
+}
+
+/// Reste du signe du diviseur, cohérent avec floor_div: -7 % 3 = 2
+fn floor_mod(a: i64, b: i64) -> i64 {
+    // wrapping_rem donne 0 pour i64::MIN % -1 au lieu de paniquer
+    let remainder = a.wrapping_rem(b);
+    if remainder != 0 && (remainder < 0) != (b < 0) {
+        remainder + b
+    } else {
+        remainder
+    }
+}
+
+/// Valeur numérique en flottant (le type est vérifié par l'appelant)
+fn as_f64(value: &Value) -> f64 {
+    match value {
+        Value::Number(n) => *n as f64,
+        Value::Float(f) => *f,
+        _ => f64::NAN,
+    }
+}
+
+/// Vérifie qu'une fonction accepte le nombre d'arguments donné
+fn check_arity(callee: &Value, count: usize) -> Result<(), Error> {
+    let (name, expected): (&str, Arity) = match callee {
+        Value::Function(function) => (function.display_name(), Arity::Exact(function.parameters.len())),
+        Value::NativeFunction { name, function } => (name, function.arity),
+        _ => return Ok(()),
+    };
+
+    if expected.accepts(count) {
+        return Ok(());
+    }
+    Err(Error::runtime_error(&format!(
+        "Fonction '{}' tana bukata {} argument(s), amma {} an bayar (fonction '{}' attend {} argument(s), mais {} fourni(s))",
+        name, expected, count, name, expected, count
+    )))
+}
+
+/// Découpe une chaîne en lettres visibles (graphèmes)
+///
+/// Une lettre suivie de signes combinants reste entière: les tons écrits avec
+/// des accents combinants (`a` + U+0300) comme les voyelles de l'ajami ne sont
+/// jamais séparés de leur lettre. C'est l'unité de toutes les opérations sur les
+/// jimla : `s[i]`, `tsawo`, `yanki`, `ga ... cikin` et `list`.
+pub fn graphemes(text: &str) -> Vec<String> {
+    let mut clusters: Vec<String> = Vec::new();
+    let mut joining = false;
+
+    for ch in text.chars() {
+        let extends = joining || is_grapheme_extender(ch);
+        match clusters.last_mut() {
+            Some(cluster) if extends => cluster.push(ch),
+            _ => clusters.push(ch.to_string()),
+        }
+        // Le ZWJ (U+200D) colle le caractère suivant au groupe courant
+        joining = ch == '\u{200D}';
+    }
+
+    clusters
+}
+
+/// Caractères qui prolongent le graphème précédent au lieu d'en commencer un nouveau
+///
+/// Table volontairement réduite (pas de dépendance Unicode) qui couvre :
+/// les diacritiques combinants latins et cyrilliques, les voyelles et signes
+/// coraniques de l'arabe (ajami), les blocs de diacritiques étendus et
+/// supplémentaires, les signes combinants pour symboles, les sélecteurs de
+/// variante, les demi-signes combinants, les teintes de peau des emoji et le
+/// ZWJ (qui colle aussi le caractère suivant, voir `graphemes`).
+///
+/// Ce n'est pas la segmentation UAX #29 complète : les syllabes hangeul
+/// décomposées, les paires d'indicateurs régionaux (drapeaux), les signes
+/// d'espacement des écritures indiennes (catégorie Mc), les caractères
+/// « prepend » et la paire CR LF comptent chacun pour plusieurs lettres.
+fn is_grapheme_extender(ch: char) -> bool {
+    matches!(ch,
+        '\u{0300}'..='\u{036F}'     // diacritiques combinants (tons: à, â)
+        | '\u{0483}'..='\u{0489}'
+        | '\u{0610}'..='\u{061A}'   // signes arabes (ajami)
+        | '\u{064B}'..='\u{065F}'
+        | '\u{0670}'
+        | '\u{06D6}'..='\u{06DC}'
+        | '\u{06DF}'..='\u{06E4}'
+        | '\u{06E7}'..='\u{06E8}'
+        | '\u{06EA}'..='\u{06ED}'
+        | '\u{1AB0}'..='\u{1AFF}'
+        | '\u{1DC0}'..='\u{1DFF}'
+        | '\u{200D}'
+        | '\u{20D0}'..='\u{20FF}'
+        | '\u{FE00}'..='\u{FE0F}'   // sélecteurs de variante
+        | '\u{FE20}'..='\u{FE2F}'
+        | '\u{1F3FB}'..='\u{1F3FF}' // teintes de peau des emoji
+    )
+}
@@ -8,6 +8,43 @@ use crate::error::Error;
 #[cfg(feature = "serde")]
 use serde::Serialize;
 
+/// Position d'un élément dans le code source (lignes et colonnes à partir de 1)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+    /// Longueur en caractères
+    pub len: usize,
+}
+
+impl Span {
+    /// Crée une nouvelle position
+    pub fn new(line: usize, column: usize, len: usize) -> Self {
+        Span { line, column, len }
+    }
+}
+
+impl Default for Span {
+    fn default() -> Self {
+        Span::new(1, 1, 0)
+    }
+}
+
+/// Token accompagné de sa position dans le code source
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct SpannedToken {
+    pub token: Token,
+    pub span: Span,
+}
+
+impl PartialEq<Token> for SpannedToken {
+    fn eq(&self, other: &Token) -> bool {
+        self.token == *other
+    }
+}
+
 /// Types de tokens dans Dabara
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
@@ -35,6 +72,16 @@ pub enum Token {
     Break,      // katse
     Continue,   // ci_gaba
 
+    // Gestion des erreurs
+    Try,        // gwada
+    Catch,      // kama
+    Finally,    // a_ƙarshe
+    Throw,      // jefa
+
+    // Gwaje-gwaje (tests)
+    Assert,     // tabbatar
+    Test,       // gwaji
+
     // Comparaisons
     Equal,      // == (daidai)
     NotEqual,   // != (ba daidai ba)
@@ -42,9 +89,19 @@ pub enum Token {
     Greater,    // > (sama)
     LessEqual,  // <= (ƙasa ko daidai)
     GreaterEqual, // >= (sama ko daidai)
+
+    // Opérateurs logiques
+    And,        // da, &&
+    Or,         // ko, ||
+    Not,        // ba, !
     
     // Méthodes inspirées de Ruby (en haoussa)
     Dot,        // . (pour appel de méthode)
+
+    // Zangon lambobi (intervalles)
+    DotDot,      // .. (fin exclue)
+    DotDotEqual, // ..= (fin incluse)
+    Step,        // taki (pas d'un intervalle)
     
     // Délimiteurs pour fonctions et listes
     LeftParen,  // (
@@ -54,6 +111,7 @@ pub enum Token {
     LeftBracket, // [ (pour listes)
     RightBracket, // ] (pour listes)
     Comma,      // ,
+    Colon,      // : (pour les dictionnaires)
     
     // Littéraux
     Identifier(String),
@@ -66,7 +124,14 @@ pub enum Token {
     Minus,      // - (rage)
     Multiply,   // * (ninka)
     Divide,     // / (raba)
+    Modulo,     // % (saura)
+    FloorDivide, // // (raba lamba cikakke)
+    Power,      // ** (ƙarfi)
     Equals,     // =
+    PlusEquals,     // +=
+    MinusEquals,    // -=
+    MultiplyEquals, // *=
+    DivideEquals,   // /=
     
     // Délimiteurs
     Newline,
@@ -105,10 +170,27 @@ impl Token {
             "cikin" => Some(Token::In),
             "katse" => Some(Token::Break),
             "ci_gaba" => Some(Token::Continue),
+            "taki" => Some(Token::Step),
+
+            // Gestion des erreurs
+            "gwada" => Some(Token::Try),
+            "kama" => Some(Token::Catch),
+            "a_ƙarshe" => Some(Token::Finally),
+            "jefa" => Some(Token::Throw),
+
+            // Gwaje-gwaje (tests)
+            "tabbatar" => Some(Token::Assert),
+            "gwaji" => Some(Token::Test),
+
+            // Opérateurs logiques
+            "da" => Some(Token::And),
+            "ko" => Some(Token::Or),
+            "ba" => Some(Token::Not),
 
             // Versions alternatives avec caractères latins (deprecated but supported)
             "kare" => Some(Token::End),      // Alternative pour ƙare
             "nada" => Some(Token::Let),      // Deprecated: use 'var' instead
+            "a_karshe" => Some(Token::Finally), // Alternative pour a_ƙarshe
             // Note: "kara" removed from keywords to allow it as method name
 
             _ => None,
@@ -121,23 +203,33 @@ pub struct Lexer {
     input: Vec<char>,
     position: usize,
     current_char: Option<char>,  
+    line: usize,
+    column: usize,
 }
 
 impl Lexer {
     /// Crée un nouveau lexer
     pub fn new(input: &str) -> Self {
         let chars: Vec<char> = input.chars().collect();
-        let current_char = chars.get(0).copied();
+        let current_char = chars.first().copied();
         
         Lexer {
             input: chars,
             position: 0,
             current_char,
+            line: 1,
+            column: 1,
         }
     }
     
     /// Avance au caractère suivant
     fn advance(&mut self) {
+        if self.current_char == Some('\n') {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
         self.position += 1;
         self.current_char = self.input.get(self.position).copied();
     }
@@ -169,7 +261,7 @@ impl Lexer {
     }
     
     /// Lit un nombre (entier ou flottant)
-    fn read_number(&mut self) -> Token {
+    fn read_number(&mut self) -> Result<Token, Error> {
         let mut number_str = String::new();
         let mut is_float = false;
 
@@ -177,7 +269,7 @@ impl Lexer {
             if ch.is_ascii_digit() {
                 number_str.push(ch);
                 self.advance();
-            } else if ch == '.' && self.peek().map_or(false, |c| c.is_ascii_digit()) {
+            } else if ch == '.' && self.peek().is_some_and(|c| c.is_ascii_digit()) {
                 // C'est un point décimal suivi d'un chiffre
                 is_float = true;
                 number_str.push(ch);
@@ -188,9 +280,11 @@ impl Lexer {
         }
 
         if is_float {
-            Token::Float(number_str.parse().unwrap_or(0.0))
+            Ok(Token::Float(number_str.parse().unwrap_or(0.0)))
         } else {
-            Token::Number(number_str.parse().unwrap_or(0))
+            number_str.parse()
+                .map(Token::Number)
+                .map_err(|_| Error::integer_literal_too_large(&number_str))
         }
     }
     
@@ -229,161 +323,242 @@ impl Lexer {
     
     /// Vérifie si un caractère est un caractère spécial haoussa
     fn is_hausa_char(&self, ch: char) -> bool {
-        match ch {
-            'ɓ' | 'ɗ' | 'ƙ' | 'ƴ' | 'ʔ' => true,
-            _ => false,
-        }
+        matches!(ch, 'ɓ' | 'ɗ' | 'ƙ' | 'ƴ' | 'ʔ')
     }
     
-    /// Lit le prochain token
-    pub fn next_token(&mut self) -> Result<Token, Error> {
+    /// Lit le prochain token avec sa position
+    pub fn next_token(&mut self) -> Result<SpannedToken, Error> {
+        // Ignorer les espaces et les commentaires avant le token
         loop {
             match self.current_char {
-                None => return Ok(Token::Eof),
-                
-                Some(' ') | Some('\t') | Some('\r') => {
-                    self.skip_whitespace();
-                }
-                
-                Some('\n') => {
-                    self.advance();
-                    return Ok(Token::Newline);
-                }
-                
-                Some('=') if self.peek() == Some('=') => {
-                    self.advance(); // Consommer le premier '='
-                    self.advance(); // Consommer le second '='
-                    return Ok(Token::Equal);
-                }
-                
-                Some('=') => {
-                    self.advance();
-                    return Ok(Token::Equals);
-                }
-                
-                Some('+') => {
-                    self.advance();
-                    return Ok(Token::Plus);
-                }
-                
-                Some('-') => {
-                    self.advance();
-                    return Ok(Token::Minus);
-                }
-                
-                Some('*') => {
-                    self.advance();
-                    return Ok(Token::Multiply);
-                }
-                
-                Some('/') => {
-                    self.advance();
-                    return Ok(Token::Divide);
-                }
-                
-                Some('#') => {
-                    self.skip_comment();
-                    continue; // Ignorer et continuer
-                }
-                
-                Some('(') => {
-                    self.advance();
-                    return Ok(Token::LeftParen);
-                }
-                
-                Some(')') => {
-                    self.advance();
-                    return Ok(Token::RightParen);
-                }
-                
-                Some('{') => {
-                    self.advance();
-                    return Ok(Token::LeftBrace);
-                }
-                
-                Some('}') => {
-                    self.advance();
-                    return Ok(Token::RightBrace);
-                }
-                
-                Some(',') => {
-                    self.advance();
-                    return Ok(Token::Comma);
-                }
-                
-                Some('[') => {
-                    self.advance();
-                    return Ok(Token::LeftBracket);
-                }
-                
-                Some(']') => {
-                    self.advance();
-                    return Ok(Token::RightBracket);
-                }
-                
-                Some('!') if self.peek() == Some('=') => {
-                    self.advance(); // Consommer '!'
-                    self.advance(); // Consommer '='
-                    return Ok(Token::NotEqual);
-                }
-                
-                Some('<') if self.peek() == Some('=') => {
-                    self.advance(); // Consommer '<'
-                    self.advance(); // Consommer '='
-                    return Ok(Token::LessEqual);
-                }
-                
-                Some('<') => {
-                    self.advance();
-                    return Ok(Token::Less);
-                }
-                
-                Some('>') if self.peek() == Some('=') => {
-                    self.advance(); // Consommer '>'
-                    self.advance(); // Consommer '='
-                    return Ok(Token::GreaterEqual);
-                }
-                
-                Some('>') => {
-                    self.advance();
-                    return Ok(Token::Greater);
-                }
-                
-                Some('"') => {
-                    let string_val = self.read_string()?;
-                    return Ok(Token::String(string_val));
-                }
-                
-                Some('.') if !self.peek().map_or(false, |c| c.is_ascii_digit()) => {
-                    // C'est un point de méthode, pas un nombre décimal
+                Some(' ') | Some('\t') | Some('\r') => self.skip_whitespace(),
+                Some('#') => self.skip_comment(),
+                _ => break,
+            }
+        }
+
+        let start = self.position;
+        let span = Span::new(self.line, self.column, 1);
+        let token = self.read_token().map_err(|e| e.with_span(span))?;
+
+        Ok(SpannedToken {
+            token,
+            span: Span::new(span.line, span.column, self.position - start),
+        })
+    }
+
+    /// Lit le token à la position courante
+    fn read_token(&mut self) -> Result<Token, Error> {
+        match self.current_char {
+            None => Ok(Token::Eof),
+            
+            Some('\n') => {
+                self.advance();
+                Ok(Token::Newline)
+            }
+            
+            Some('=') if self.peek() == Some('=') => {
+                self.advance(); // Consommer le premier '='
+                self.advance(); // Consommer le second '='
+                Ok(Token::Equal)
+            }
+            
+            Some('=') => {
+                self.advance();
+                Ok(Token::Equals)
+            }
+            
+            Some('+') if self.peek() == Some('=') => {
+                self.advance();
+                self.advance();
+                Ok(Token::PlusEquals)
+            }
+            
+            Some('+') => {
+                self.advance();
+                Ok(Token::Plus)
+            }
+            
+            Some('-') if self.peek() == Some('=') => {
+                self.advance();
+                self.advance();
+                Ok(Token::MinusEquals)
+            }
+            
+            Some('-') => {
+                self.advance();
+                Ok(Token::Minus)
+            }
+            
+            Some('*') if self.peek() == Some('=') => {
+                self.advance();
+                self.advance();
+                Ok(Token::MultiplyEquals)
+            }
+            
+            Some('*') if self.peek() == Some('*') => {
+                self.advance(); // Consommer le premier '*'
+                self.advance(); // Consommer le second '*'
+                Ok(Token::Power)
+            }
+            
+            Some('*') => {
+                self.advance();
+                Ok(Token::Multiply)
+            }
+            
+            Some('/') if self.peek() == Some('=') => {
+                self.advance();
+                self.advance();
+                Ok(Token::DivideEquals)
+            }
+            
+            Some('/') if self.peek() == Some('/') => {
+                self.advance(); // Consommer le premier '/'
+                self.advance(); // Consommer le second '/'
+                Ok(Token::FloorDivide)
+            }
+            
+            Some('/') => {
+                self.advance();
+                Ok(Token::Divide)
+            }
+            
+            Some('%') => {
+                self.advance();
+                Ok(Token::Modulo)
+            }
+            
+            Some('(') => {
+                self.advance();
+                Ok(Token::LeftParen)
+            }
+            
+            Some(')') => {
+                self.advance();
+                Ok(Token::RightParen)
+            }
+            
+            Some('{') => {
+                self.advance();
+                Ok(Token::LeftBrace)
+            }
+            
+            Some('}') => {
+                self.advance();
+                Ok(Token::RightBrace)
+            }
+            
+            Some(',') => {
+                self.advance();
+                Ok(Token::Comma)
+            }
+            
+            Some(':') => {
+                self.advance();
+                Ok(Token::Colon)
+            }
+            
+            Some('[') => {
+                self.advance();
+                Ok(Token::LeftBracket)
+            }
+            
+            Some(']') => {
+                self.advance();
+                Ok(Token::RightBracket)
+            }
+            
+            Some('!') if self.peek() == Some('=') => {
+                self.advance(); // Consommer '!'
+                self.advance(); // Consommer '='
+                Ok(Token::NotEqual)
+            }
+            
+            Some('!') => {
+                self.advance();
+                Ok(Token::Not)
+            }
+            
+            Some('&') if self.peek() == Some('&') => {
+                self.advance(); // Consommer le premier '&'
+                self.advance(); // Consommer le second '&'
+                Ok(Token::And)
+            }
+            
+            Some('|') if self.peek() == Some('|') => {
+                self.advance(); // Consommer le premier '|'
+                self.advance(); // Consommer le second '|'
+                Ok(Token::Or)
+            }
+            
+            Some('<') if self.peek() == Some('=') => {
+                self.advance(); // Consommer '<'
+                self.advance(); // Consommer '='
+                Ok(Token::LessEqual)
+            }
+            
+            Some('<') => {
+                self.advance();
+                Ok(Token::Less)
+            }
+            
+            Some('>') if self.peek() == Some('=') => {
+                self.advance(); // Consommer '>'
+                self.advance(); // Consommer '='
+                Ok(Token::GreaterEqual)
+            }
+            
+            Some('>') => {
+                self.advance();
+                Ok(Token::Greater)
+            }
+            
+            Some('"') => {
+                let string_val = self.read_string()?;
+                Ok(Token::String(string_val))
+            }
+            
+            Some('.') if self.peek() == Some('.') => {
+                self.advance(); // Consommer le premier '.'
+                self.advance(); // Consommer le second '.'
+                if self.current_char == Some('=') {
                     self.advance();
-                    return Ok(Token::Dot);
+                    Ok(Token::DotDotEqual)
+                } else {
+                    Ok(Token::DotDot)
                 }
+            }
 
-                Some(ch) if ch.is_ascii_digit() || (ch == '.' && self.peek().map_or(false, |c| c.is_ascii_digit())) => {
-                    return Ok(self.read_number());
-                }
+            Some('.') if !self.peek().is_some_and(|c| c.is_ascii_digit()) => {
+                // C'est un point de méthode, pas un nombre décimal
+                self.advance();
+                Ok(Token::Dot)
+            }
 
-                Some(ch) if ch.is_alphabetic() || self.is_hausa_char(ch) => {
-                    let identifier = self.read_identifier();
-                    
-                    // Vérifier si c'est un mot-clé
-                    if let Some(token) = Token::from_keyword(&identifier) {
-                        return Ok(token);
-                    } else {
-                        return Ok(Token::Identifier(identifier));
-                    }
-                }
+            Some(ch) if ch.is_ascii_digit() || (ch == '.' && self.peek().is_some_and(|c| c.is_ascii_digit())) => {
+                self.read_number()
+            }
+
+            Some(ch) if ch.is_alphabetic() || self.is_hausa_char(ch) => {
+                let identifier = self.read_identifier();
                 
-                Some(ch) => {
-                    return Err(Error::unknown_token(&ch.to_string()));
+                // Vérifier si c'est un mot-clé
+                if let Some(token) = Token::from_keyword(&identifier) {
+                    Ok(token)
+                } else {
+                    Ok(Token::Identifier(identifier))
                 }
             }
+            
+            Some(ch) => {
+                Err(Error::unknown_token(&ch.to_string()))
+            }
         }
     }
     
     /// Tokenise tout le code source
-    pub fn tokenize_all(&mut self) -> Result<Vec<Token>, Error> {
+    pub fn tokenize_all(&mut self) -> Result<Vec<SpannedToken>, Error> {
         let mut tokens = Vec::new();
         
         loop {
@@ -401,7 +576,7 @@ impl Lexer {
 }
 
 /// Fonction utilitaire pour tokeniser une chaîne
-pub fn tokenize(input: &str) -> Result<Vec<Token>, Error> {
+pub fn tokenize(input: &str) -> Result<Vec<SpannedToken>, Error> {
     let mut lexer = Lexer::new(input);
     lexer.tokenize_all()
 }
\ No newline at end of file
@@ -8,20 +8,29 @@
 //! - `lexer`: Tokenisation du code source avec support Unicode Hausa
 //! - `parser`: Construction de l'arbre syntaxique abstrait (AST)
 //! - `interpreter`: Moteur d'exécution des programmes Dabara
+//! - `host`: Entrées et sorties des programmes (terminal, mémoire, navigateur)
 //! - `error`: Gestion des erreurs avec messages en haoussa
+//! - `decimal`: Nombres décimaux exacts pour les calculs d'argent (kuɗi)
 //! - `stdlib`: Bibliothèque standard avec fonctions utilitaires
+//! - `repl`: Session interactive (`dabara repl`)
+//! - `testing`: Blocs de test `gwaji` (`dabara test`)
 //! - `wasm`: Bindings WebAssembly pour exécution dans le navigateur
 
 pub mod error;
 pub mod lexer;
 pub mod parser;
 pub mod interpreter;
+pub mod host;
+pub mod decimal;
 pub mod stdlib;
+pub mod repl;
+pub mod testing;
 
 #[cfg(feature = "wasm")]
 pub mod wasm;
 
-pub use error::Error;
-pub use lexer::{Token, tokenize};
-pub use parser::{Statement, Expression, BinaryOperator, Program, parse};
+pub use error::{Error, ErrorCode, Frame};
+pub use lexer::{Span, SpannedToken, Token, tokenize};
+pub use parser::{Statement, StatementKind, Expression, ExpressionKind, BinaryOperator, Program, parse, parse_statements};
 pub use interpreter::{Value, Interpreter};
+pub use decimal::{Decimal, RoundingMode};
@@ -1,15 +1,44 @@
 //! Dabara Programming Language v0.0.1
 //! 
 //! Interface en ligne de commande pour exécuter des programmes Dabara.
-//! Usage: dabara <fichier.ha>
+//! Usage: dabara [--max-steps N] [--timeout SECONDES] [--input FICHIER [--transcript]] <fichier.ha>
+//!        dabara repl
+//!        dabara test <dossier>
 
-use clap::{Arg, Command};
+use clap::{value_parser, Arg, ArgAction, Command};
 use std::fs;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::Path;
 use std::process;
+use std::thread;
+use std::time::Duration;
 
 use dabara::{tokenize, parse, Interpreter, Error};
+use dabara::error::RenderStyle;
+use dabara::host::{ScriptedHost, StdHost};
+use dabara::repl::{Repl, ReplStep};
+use dabara::testing::{find_test_files, run_tests, TEST_FILE_SUFFIX};
+
+/// Taille de la pile du fil qui exécute l'interpréteur
+///
+/// Chaque appel Dabara consomme plusieurs appels Rust récursifs; la pile par défaut
+/// du fil principal déborderait bien avant la limite `DEFAULT_MAX_CALL_DEPTH`.
+const INTERPRETER_STACK_SIZE: usize = 256 * 1024 * 1024;
 
 fn main() {
+    let cli = thread::Builder::new()
+        .name("dabara".to_string())
+        .stack_size(INTERPRETER_STACK_SIZE)
+        .spawn(run_cli)
+        .expect("Ba za a iya fara interpréteur ba (failed to spawn interpreter thread)");
+
+    if cli.join().is_err() {
+        process::exit(101);
+    }
+}
+
+/// Analyse la ligne de commande et lance le programme ou la session interactive
+fn run_cli() {
     let matches = Command::new("dabara")
         .version("0.1.2")
         .about("Dabara Programming Language - Hausa syntax for everyone / Yaren shirye-shirye ta Dabara - Kalmar Hausa don kowa")
@@ -19,8 +48,53 @@ fn main() {
              .required(true)
              .index(1)
              .value_name("FICHIER"))
+        .arg(Arg::new("max-steps")
+             .long("max-steps")
+             .help("Iyakar matakai (tours de boucle et appels) / Nombre maximal de pas")
+             .value_name("N")
+             .value_parser(value_parser!(u64)))
+        .arg(Arg::new("timeout")
+             .long("timeout")
+             .help("Iyakar lokaci a daƙiƙa / Durée maximale en secondes")
+             .value_name("SECONDES")
+             .value_parser(parse_timeout))
+        .arg(Arg::new("input")
+             .long("input")
+             .help("Fayil mai amsoshin karɓa, layi ɗaya ga kowace tambaya / Réponses préparées pour karɓa")
+             .value_name("FICHIER"))
+        .arg(Arg::new("transcript")
+             .long("transcript")
+             .help("Nuna kowace amsa bayan tambayarta / Affiche chaque réponse après son invite")
+             .action(ArgAction::SetTrue)
+             .requires("input"))
+        .subcommand_negates_reqs(true)
+        .subcommand(Command::new("repl")
+             .about("Zaman hulɗa kai tsaye / Session interactive"))
+        .subcommand(Command::new("test")
+             .about("Gudanar da gwaje-gwaje na fayilolin *_test.ha / Exécute les blocs gwaji")
+             .arg(Arg::new("path")
+                  .help("Jakar gwaje-gwaje ko fayil ɗaya / Dossier de tests ou fichier")
+                  .required(true)
+                  .value_name("DOSSIER")))
         .get_matches();
 
+    if matches.subcommand_matches("repl").is_some() {
+        run_repl();
+        return;
+    }
+
+    if let Some(test) = matches.subcommand_matches("test") {
+        let steps = matches.get_one::<u64>("max-steps").copied();
+        let time = matches.get_one::<Duration>("timeout").copied();
+        let path = test.get_one::<String>("path").unwrap();
+
+        let all_passed = run_test_command(Path::new(path), diagnostic_style(), |interpreter| {
+            interpreter.set_step_limit(steps);
+            interpreter.set_time_limit(time);
+        });
+        process::exit(if all_passed { 0 } else { 1 });
+    }
+
     let filename = matches.get_one::<String>("file").unwrap();
     
     // Vérifier l'extension du fichier
@@ -30,19 +104,165 @@ fn main() {
         process::exit(1);
     }
     
+    let mut interpreter = Interpreter::new();
+    interpreter.set_step_limit(matches.get_one::<u64>("max-steps").copied());
+    interpreter.set_time_limit(matches.get_one::<Duration>("timeout").copied());
+
+    // Rejouer une entrée préparée au lieu de lire le terminal
+    if let Some(input) = matches.get_one::<String>("input") {
+        let Ok(text) = fs::read_to_string(input) else {
+            eprintln!("{}", Error::file_not_found(input).render("", input, diagnostic_style()));
+            process::exit(1);
+        };
+        let host = ScriptedHost::from_text(StdHost, &text).with_transcript(matches.get_flag("transcript"));
+        interpreter.set_host(Box::new(host));
+    }
+
     // Exécuter le programme
-    if let Err(error) = run_program(filename) {
-        eprintln!("{}", error);
+    if let Err(diagnostic) = run_program(filename, interpreter, diagnostic_style()) {
+        eprintln!("{}", diagnostic);
         process::exit(1);
     }
 }
 
-/// Exécute un programme Dabara
-fn run_program(filename: &str) -> Result<(), Error> {
+/// Lit une durée en secondes, éventuellement décimale (`--timeout 2.5`)
+fn parse_timeout(value: &str) -> Result<Duration, String> {
+    value.parse::<f64>()
+        .ok()
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        .ok_or_else(|| format!("'{}' ba adadin daƙiƙa ba ne (not a number of seconds)", value))
+}
+
+/// Lance la session interactive jusqu'à `:quit` ou la fin de l'entrée
+fn run_repl() {
+    println!("Dabara REPL v{} - rubuta :help don taimako, :quit don fita", env!("CARGO_PKG_VERSION"));
+
+    let mut repl = Repl::new(diagnostic_style());
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+
+    loop {
+        print!("{}", repl.prompt());
+        io::stdout().flush().unwrap();
+
+        let line = match lines.next() {
+            Some(Ok(line)) => line,
+            _ => {
+                println!();
+                break;
+            }
+        };
+
+        match repl.feed_line(&line) {
+            ReplStep::Incomplete | ReplStep::Done(None) => {}
+            ReplStep::Done(Some(output)) => println!("{}", output),
+            ReplStep::Failed(diagnostic) => eprintln!("{}", diagnostic),
+            ReplStep::Quit => break,
+        }
+    }
+}
+
+/// Exécute les blocs gwaji des fichiers `*_test.ha` et affiche un résumé
+///
+/// Retourne `true` si tous les tests ont réussi.
+fn run_test_command(path: &Path, style: RenderStyle, configure: impl Fn(&mut Interpreter)) -> bool {
+    let files = match find_test_files(path) {
+        Ok(files) if !files.is_empty() => files,
+        Ok(_) => {
+            eprintln!("Ba a sami fayilolin gwaji (*{}) a '{}' ba (no test files found)", TEST_FILE_SUFFIX, path.display());
+            return false;
+        }
+        Err(_) => {
+            let filename = path.display().to_string();
+            eprintln!("{}", Error::file_not_found(&filename).render("", &filename, style));
+            return false;
+        }
+    };
+
+    let (mut passed, mut failed, mut broken_files) = (0, 0, 0);
+
+    for file in &files {
+        let filename = file.display().to_string();
+        println!("{}", filename);
+
+        let parsed = fs::read_to_string(file)
+            .map_err(|_| (String::new(), Error::file_not_found(&filename)))
+            .and_then(|source| match tokenize(&source).and_then(parse) {
+                Ok(program) => Ok((source, program)),
+                Err(error) => Err((source, error)),
+            });
+        let (source, program) = match parsed {
+            Ok(parsed) => parsed,
+            Err((source, error)) => {
+                broken_files += 1;
+                println!("  [kuskure] ba a iya karanta fayil ɗin ba (file could not be loaded)");
+                println!("{}", indent(&error.render(&source, &filename, style), 4));
+                continue;
+            }
+        };
+
+        let outcomes = run_tests(&program, &configure);
+        if outcomes.is_empty() {
+            println!("  babu gwaji a wannan fayil (no gwaji blocks)");
+        }
+
+        for outcome in outcomes {
+            match &outcome.error {
+                None => {
+                    passed += 1;
+                    println!("  [ya wuce] {}", outcome.name);
+                }
+                Some(error) => {
+                    failed += 1;
+                    println!("  [ya kasa] {}", outcome.name);
+                    println!("{}", indent(&error.render(&source, &filename, style), 4));
+                    if !outcome.output.is_empty() {
+                        println!("    fitarwa (output):");
+                        println!("{}", indent(outcome.output.trim_end(), 6));
+                    }
+                }
+            }
+        }
+    }
+
+    println!();
+    println!(
+        "Sakamako: gwaje-gwaje {}, sun wuce {}, sun kasa {} ({} tests: {} passed, {} failed)",
+        passed + failed, passed, failed, passed + failed, passed, failed
+    );
+    if broken_files > 0 {
+        println!("Fayiloli {} ba su karantu ba ({} files could not be loaded)", broken_files, broken_files);
+    }
+
+    failed == 0 && broken_files == 0
+}
+
+/// Décale chaque ligne d'un texte de `width` espaces
+fn indent(text: &str, width: usize) -> String {
+    let padding = " ".repeat(width);
+    text.lines().map(|line| format!("{}{}", padding, line)).collect::<Vec<_>>().join("\n")
+}
+
+/// Choisit le rendu des diagnostics : couleurs seulement dans un terminal
+fn diagnostic_style() -> RenderStyle {
+    if std::io::stderr().is_terminal() && std::env::var_os("NO_COLOR").is_none() {
+        RenderStyle::Color
+    } else {
+        RenderStyle::Plain
+    }
+}
+
+/// Exécute un programme Dabara et retourne le diagnostic en cas d'erreur
+fn run_program(filename: &str, interpreter: Interpreter, style: RenderStyle) -> Result<(), String> {
     // Lire le fichier
     let source = fs::read_to_string(filename)
-        .map_err(|_| Error::file_not_found(filename))?;
-    
+        .map_err(|_| Error::file_not_found(filename).render("", filename, style))?;
+
+    execute_source(&source, interpreter).map_err(|error| error.render(&source, filename, style))
+}
+
+/// Tokenise, analyse et exécute un code source Dabara
+fn execute_source(source: &str, mut interpreter: Interpreter) -> Result<(), Error> {
     // Afficher le contenu du fichier en mode debug
     if std::env::var("DABARA_DEBUG").is_ok() {
         println!("=== Code source ===");
@@ -51,7 +271,7 @@ fn run_program(filename: &str) -> Result<(), Error> {
     }
     
     // Tokeniser
-    let tokens = tokenize(&source)?;
+    let tokens = tokenize(source)?;
     
     if std::env::var("DABARA_DEBUG").is_ok() {
         println!("=== Tokens ===");
@@ -71,7 +291,6 @@ fn run_program(filename: &str) -> Result<(), Error> {
     }
     
     // Exécuter
-    let mut interpreter = Interpreter::new();
     interpreter.execute(ast)?;
     
     Ok(())
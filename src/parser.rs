@@ -3,7 +3,7 @@
 //! Ce module implémente l'analyseur syntaxique qui convertit les tokens
 //! en arbre syntaxique abstrait (AST).
 
-use crate::lexer::Token;
+use crate::lexer::{Span, SpannedToken, Token};
 use crate::error::Error;
 
 #[cfg(feature = "serde")]
@@ -16,10 +16,25 @@ pub struct Program {
     pub statements: Vec<Statement>,
 }
 
+/// Statement avec sa position dans le code source
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Statement {
+    pub kind: StatementKind,
+    pub span: Span,
+}
+
+impl Statement {
+    /// Crée un statement à la position donnée
+    pub fn new(kind: StatementKind, span: Span) -> Self {
+        Statement { kind, span }
+    }
+}
+
 /// Types de statements dans Dabara
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum Statement {
+pub enum StatementKind {
     /// Déclaration de variable: naɗa nom = expression    
     Let { name: String, value: Expression },
     /// Instruction d'affichage: rubuta expression
@@ -46,21 +61,82 @@ pub enum Statement {
         body: Vec<Statement>,
     },
     /// Boucle for: ga variable cikin iterable { corps }
+    ///
+    /// Avec deux variables (`ga i, abu cikin jeri`), `index` reçoit la position.
     For {
+        index: Option<String>,
         variable: String,
         iterable: Expression,
         body: Vec<Statement>,
     },
+    /// Affectation d'une variable existante: nom = expression, nom += expression
+    Assign {
+        name: String,
+        operator: Option<CompoundOperator>,
+        value: Expression,
+    },
+    /// Gestion d'erreurs: gwada { } kama kuskure { } a_ƙarshe { }
+    ///
+    /// `catch_variable` nomme l'erreur rattrapée dans `catch_body`.
+    Try {
+        body: Vec<Statement>,
+        catch_variable: Option<String>,
+        catch_body: Option<Vec<Statement>>,
+        finally_body: Option<Vec<Statement>>,
+    },
+    /// Lever une erreur: jefa "saƙo"
+    Throw(Expression),
+    /// Assertion: tabbatar condition, "saƙo"
+    Assert {
+        condition: Expression,
+        message: Option<Expression>,
+    },
+    /// Bloc de test: gwaji "suna" { corps }
+    ///
+    /// Ignoré par une exécution ordinaire; `dabara test` exécute chaque bloc à part.
+    Test { name: String, body: Vec<Statement> },
+    /// Affectation par index ou par clé: jeri[i] = v, m["clé"] += v, grid[i][j] = v
+    IndexAssign {
+        object: Expression,
+        index: Expression,
+        operator: Option<CompoundOperator>,
+        value: Expression,
+    },
     /// Instruction break: katse
     Break,
     /// Instruction continue: ci_gaba
     Continue,
 }
 
+/// Opérateur d'une affectation composée (`+=`, `-=`, `*=`, `/=`) et sa position
+///
+/// La cible n'est évaluée qu'une fois : `jeri[f()] += 1` n'appelle `f` qu'une fois.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct CompoundOperator {
+    pub operator: BinaryOperator,
+    pub span: Span,
+}
+
+/// Expression avec sa position dans le code source
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Expression {
+    pub kind: ExpressionKind,
+    pub span: Span,
+}
+
+impl Expression {
+    /// Crée une expression à la position donnée
+    pub fn new(kind: ExpressionKind, span: Span) -> Self {
+        Expression { kind, span }
+    }
+}
+
 /// Types d'expressions dans Dabara
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum Expression {
+pub enum ExpressionKind {
     /// Identificateur de variable
     Identifier(String),
     /// Nombre entier
@@ -71,6 +147,8 @@ pub enum Expression {
     Boolean(bool),
     /// Liste de valeurs: [element1, element2, ...]
     List(Vec<Expression>),
+    /// Dictionnaire: {clé: valeur, ...}
+    Map(Vec<(Expression, Expression)>),
     /// Opération binaire
     BinaryOp {                                   
         left: Box<Expression>,
@@ -82,14 +160,24 @@ pub enum Expression {
         name: String,
         arguments: Vec<Expression>,
     },
+    /// Appel d'une valeur de fonction: f(1)(2), ayyuka[0](x)
+    Call {
+        callee: Box<Expression>,
+        arguments: Vec<Expression>,
+    },
+    /// Fonction anonyme: aiki(x) { mayar x * 2 }
+    Lambda {
+        parameters: Vec<String>,
+        body: Vec<Statement>,
+    },
     /// Appel de mètode (style Ruby en haoussa)  /// e.g => yin mutane("Musa")
     MethodCall {
         receiver: Box<Expression>,
         method: String,
         arguments: Vec<Expression>,
     },
-    /// Entrée utilisateur
-    Input,
+    /// Entrée utilisateur, avec une invite facultative: karɓa, karɓa("Sunanka? ")
+    Input(Option<Box<Expression>>),
     /// Indexation: expression[index]
     Index {
         object: Box<Expression>,
@@ -102,6 +190,13 @@ pub enum Expression {
         operator: UnaryOperator,
         operand: Box<Expression>,
     },
+    /// Zango de lambobi: 1..10, 1..=10, 0..100 taki 5
+    Range {
+        start: Box<Expression>,
+        end: Box<Expression>,
+        inclusive: bool,
+        step: Option<Box<Expression>>,
+    },
 }
 
 /// Opérateurs unaires supportés
@@ -126,6 +221,12 @@ pub enum BinaryOperator {
     Multiply,
     /// Division (/)
     Divide,
+    /// Reste de la division (%), du signe du diviseur
+    Modulo,
+    /// Division entière arrondie vers le bas (//)
+    FloorDivide,
+    /// Puissance (**), associative à droite
+    Power,
     /// Concaténation (+)
     Concat,
     /// Égalité (==)
@@ -140,32 +241,49 @@ pub enum BinaryOperator {
     LessEqual,
     /// Supérieur ou égal (>=)
     GreaterEqual,
+    /// Et logique (da, &&), évalué en court-circuit
+    And,
+    /// Ou logique (ko, ||), évalué en court-circuit
+    Or,
 }
 
+/// Nombre maximal de niveaux imbriqués (parenthèses, blocs, opérateurs unaires,
+/// maillons d'une chaîne `a + b + c` ou `a.b().c()`, ...)
+///
+/// Le parser et l'interpréteur descendent récursivement dans l'arbre : sans limite,
+/// un code trop imbriqué ferait déborder la pile au lieu de produire une erreur.
+pub const MAX_NESTING_DEPTH: usize = 100;
+
 /// Parser pour construire l'AST
 pub struct Parser {
-    tokens: Vec<Token>,
+    tokens: Vec<SpannedToken>,
     position: usize,  
     current_token: Token,
+    current_span: Span,
+    /// Niveau d'imbrication en cours
+    depth: usize,
 }
 
 impl Parser {
     /// Crée un nouveau parser
-    pub fn new(mut tokens: Vec<Token>) -> Result<Self, Error> {
+    pub fn new(mut tokens: Vec<SpannedToken>) -> Result<Self, Error> {
         // Enlever les newlines inutiles au début et à la fin
         let original_len = tokens.len();
-        tokens.retain(|token| *token != Token::Newline || original_len <= 2);
+        tokens.retain(|token| token.token != Token::Newline || original_len <= 2);
         
         if tokens.is_empty() {
-            tokens.push(Token::Eof);
+            tokens.push(SpannedToken { token: Token::Eof, span: Span::default() });
         }
         
-        let current_token = tokens[0].clone();
+        let current_token = tokens[0].token.clone();
+        let current_span = tokens[0].span;
         
         Ok(Parser {
             tokens,
             position: 0,
             current_token,
+            current_span,
+            depth: 0,
         })
     }
     
@@ -174,7 +292,8 @@ impl Parser {
         self.position += 1;
         
         if self.position < self.tokens.len() {
-            self.current_token = self.tokens[self.position].clone();
+            self.current_token = self.tokens[self.position].token.clone();
+            self.current_span = self.tokens[self.position].span;
         } else {
             self.current_token = Token::Eof;
         }
@@ -220,13 +339,21 @@ impl Parser {
             Token::Minus |
             Token::Multiply |
             Token::Divide |
+            Token::Modulo |
+            Token::FloorDivide |
+            Token::Power |
             Token::Dot |
+            Token::DotDot |
+            Token::DotDotEqual |
+            Token::Step |
             Token::Equal |
             Token::NotEqual |
             Token::Less |
             Token::Greater |
             Token::LessEqual |
             Token::GreaterEqual |
+            Token::And |
+            Token::Or |
             Token::Else |
             Token::ElseIf
         )
@@ -271,8 +398,59 @@ impl Parser {
         Ok(Program { statements })
     }
     
+    /// Parse une suite de statements sans l'enveloppe 'fara' / 'ƙare'
+    /// (mode interactif). Une expression seule est acceptée comme statement.
+    pub fn parse_statements(&mut self) -> Result<Program, Error> {
+        let mut statements = Vec::new();
+
+        while self.current_token != Token::Eof {
+            if self.current_token == Token::Newline {
+                self.advance()?;
+                continue;
+            }
+
+            let statement = if self.is_expression_start() {
+                let span = self.current_span;
+                let expression = self.parse_expression()?;
+                Statement::new(StatementKind::Expression(expression), span)
+            } else {
+                self.parse_statement()?
+            };
+            statements.push(statement);
+        }
+
+        Ok(Program { statements })
+    }
+
+    /// Vérifie si le token actuel peut commencer une expression
+    fn is_expression_start(&self) -> bool {
+        matches!(self.current_token,
+            Token::Number(_) |
+            Token::Float(_) |
+            Token::String(_) |
+            Token::True |
+            Token::False |
+            Token::Input |
+            Token::Minus |
+            Token::Plus |
+            Token::Not |
+            Token::LeftParen |
+            Token::LeftBracket |
+            Token::LeftBrace
+        )
+    }
+
     /// Parse un statement
+    ///
+    /// Chaque statement imbriqué (corps de idan, maimaita, aiki, ...) compte pour un niveau.
     fn parse_statement(&mut self) -> Result<Statement, Error> {
+        self.nested(Self::parse_statement_kind)
+    }
+
+    /// Parse un statement selon son premier token
+    fn parse_statement_kind(&mut self) -> Result<Statement, Error> {
+        let span = self.current_span;
+
         match &self.current_token {
             Token::Let => self.parse_let_statement(),
             Token::Print => self.parse_print_statement(),
@@ -281,71 +459,143 @@ impl Parser {
             Token::Return => self.parse_return_statement(),
             Token::While => self.parse_while_statement(),
             Token::For => self.parse_for_statement(),
+            Token::Try => self.parse_try_statement(),
+            Token::Throw => {
+                self.advance()?; // Consommer 'jefa'
+                let value = self.parse_expression()?;
+                Ok(Statement::new(StatementKind::Throw(value), span))
+            }
+            Token::Assert => self.parse_assert_statement(),
+            Token::Test => self.parse_test_block(),
             Token::Break => {
                 self.advance()?;
-                Ok(Statement::Break)
+                Ok(Statement::new(StatementKind::Break, span))
             }
             Token::Continue => {
                 self.advance()?;
-                Ok(Statement::Continue)
+                Ok(Statement::new(StatementKind::Continue, span))
             }
             // Si c'est un identificateur, cela peut être un appel de fonction
+            // ou une affectation
             Token::Identifier(_) => {
                 let expression = self.parse_expression()?;
-                Ok(Statement::Expression(expression))
+
+                if self.is_assignment_operator() {
+                    return self.parse_assignment(expression, span);
+                }
+
+                Ok(Statement::new(StatementKind::Expression(expression), span))
+            }
+            // `da = 1`, `taki += 2` : un mot réservé pris pour une variable
+            Token::And | Token::Or | Token::Not | Token::Step => {
+                let word = reserved_name(&self.current_token).unwrap_or_default();
+                Err(Error::reserved_word(word).with_span(span))
             }
             _ => Err(Error::expected_statement()),
         }
     }
     
+    /// Vérifie si le token actuel est `=` ou une affectation composée (`+=`, `-=`, ...)
+    fn is_assignment_operator(&self) -> bool {
+        matches!(self.current_token,
+            Token::Equals |
+            Token::PlusEquals |
+            Token::MinusEquals |
+            Token::MultiplyEquals |
+            Token::DivideEquals
+        )
+    }
+
+    /// Parse une affectation dont la cible a déjà été lue: cible = expression
+    ///
+    /// Une affectation composée `x += e` garde son opérateur : l'interpréteur lit,
+    /// combine et écrit la cible sans l'évaluer deux fois.
+    fn parse_assignment(&mut self, target: Expression, span: Span) -> Result<Statement, Error> {
+        let operator_span = self.current_span;
+        let operator = match self.current_token {
+            Token::PlusEquals => Some(BinaryOperator::Add),
+            Token::MinusEquals => Some(BinaryOperator::Subtract),
+            Token::MultiplyEquals => Some(BinaryOperator::Multiply),
+            Token::DivideEquals => Some(BinaryOperator::Divide),
+            _ => None,
+        }.map(|operator| CompoundOperator { operator, span: operator_span });
+        self.advance()?; // Consommer '=' ou l'opérateur composé
+
+        let value = self.parse_expression()?;
+
+        match target.kind {
+            ExpressionKind::Identifier(name) => {
+                Ok(Statement::new(StatementKind::Assign { name, operator, value }, span))
+            }
+            ExpressionKind::Index { object, index } => {
+                Ok(Statement::new(StatementKind::IndexAssign {
+                    object: *object,
+                    index: *index,
+                    operator,
+                    value,
+                }, span))
+            }
+            _ => Err(Error::invalid_assignment_target().with_span(target.span)),
+        }
+    }
+    
     /// Parse une déclaration de variable: naɗa nom = expression
     fn parse_let_statement(&mut self) -> Result<Statement, Error> {
+        let span = self.current_span;
         self.advance()?; // Consommer 'naɗa'
         
-        let name = match &self.current_token {
-            Token::Identifier(name) => {
-                let var_name = name.clone();
-                self.advance()?;
-                var_name
-            }
-            _ => return Err(Error::unexpected_token("identifier", &format!("{:?}", self.current_token))),
-        };
+        let name = self.parse_name()?;
         
         self.expect_token(Token::Equals)?;
         
         let value = self.parse_expression()?;
         
-        Ok(Statement::Let { name, value })
+        Ok(Statement::new(StatementKind::Let { name, value }, span))
     }
     
     /// Parse une instruction d'affichage: rubuta expression
     fn parse_print_statement(&mut self) -> Result<Statement, Error> {
+        let span = self.current_span;
         self.advance()?; // Consommer 'rubuta'
         
         let expression = self.parse_expression()?;
         
-        Ok(Statement::Print(expression))
+        Ok(Statement::new(StatementKind::Print(expression), span))
     }
     
     /// Parse une définition de fonction: aiki nom(paramètres) { corps }
     fn parse_function_definition(&mut self) -> Result<Statement, Error> {
+        let span = self.current_span;
         self.advance()?; // Consommer 'aiki'
         
-        let name = match &self.current_token {
-            Token::Identifier(name) => {
-                let func_name = name.clone();
-                self.advance()?;
-                func_name
-            }
-            _ => return Err(Error::unexpected_token("identifier", &format!("{:?}", self.current_token))),
-        };
-        
+        let name = self.parse_name()?;
+
+        let (parameters, body) = self.parse_parameters_and_body()?;
+
+        Ok(Statement::new(StatementKind::FunctionDef { name, parameters, body }, span))
+    }
+
+    /// Parse une fonction anonyme: aiki(paramètres) { corps }
+    fn parse_lambda_expression(&mut self) -> Result<Expression, Error> {
+        let span = self.current_span;
+        self.advance()?; // Consommer 'aiki'
+
+        let (parameters, body) = self.parse_parameters_and_body()?;
+
+        Ok(Expression::new(ExpressionKind::Lambda { parameters, body }, span))
+    }
+
+    /// Parse les paramètres et le corps d'une fonction: (a, b) { corps }
+    fn parse_parameters_and_body(&mut self) -> Result<(Vec<String>, Vec<Statement>), Error> {
         self.expect_token(Token::LeftParen)?;
         
         let mut parameters = Vec::new();
         
         // Parse les paramètres
         while self.current_token != Token::RightParen {
+            if let Some(word) = reserved_name(&self.current_token) {
+                return Err(Error::reserved_word(word).with_span(self.current_span));
+            }
             if let Token::Identifier(param) = &self.current_token {
                 parameters.push(param.clone());
                 self.advance()?;
@@ -386,11 +636,12 @@ impl Parser {
         
         self.expect_token(Token::RightBrace)?;
         
-        Ok(Statement::FunctionDef { name, parameters, body })
+        Ok((parameters, body))
     }
     
     /// Parse une condition if/else/elseif: idan condition { ... } amma { ... }
     fn parse_if_statement(&mut self) -> Result<Statement, Error> {
+        let span = self.current_span;
         self.advance()?; // Consommer 'idan'
         
         let condition = self.parse_expression()?;
@@ -424,6 +675,7 @@ impl Parser {
         
         // Vérifier s'il y a une clause else ou elseif
         let else_branch = if self.current_token == Token::Else {
+            let else_span = self.current_span;
             self.advance()?; // Consommer 'amma'
             
             self.expect_token(Token::LeftBrace)?;
@@ -454,37 +706,39 @@ impl Parser {
             self.expect_token(Token::RightBrace)?;
             
             // Créer un If statement fictif avec condition true pour exécuter else_statements
-            Some(Box::new(Statement::If {
-                condition: Expression::Boolean(true),
+            Some(Box::new(Statement::new(StatementKind::If {
+                condition: Expression::new(ExpressionKind::Boolean(true), else_span),
                 then_branch: else_statements,
                 else_branch: None,
-            }))
+            }, else_span)))
         } else if self.current_token == Token::ElseIf {
             // Parse elseif comme un if imbriqué
-            let elseif_statement = self.parse_if_statement()?;
+            let elseif_statement = self.nested(Self::parse_if_statement)?;
             Some(Box::new(elseif_statement))
         } else {
             None
         };
         
-        Ok(Statement::If {
+        Ok(Statement::new(StatementKind::If {
             condition,
             then_branch,
             else_branch,
-        })
+        }, span))
     }
     
     /// Parse un statement de retour: mayar expression
     fn parse_return_statement(&mut self) -> Result<Statement, Error> {
+        let span = self.current_span;
         self.advance()?; // Consommer 'mayar'
 
         let expression = self.parse_expression()?;
 
-        Ok(Statement::Return(expression))
+        Ok(Statement::new(StatementKind::Return(expression), span))
     }
 
     /// Parse une boucle while: maimaita (condition) { corps }
     fn parse_while_statement(&mut self) -> Result<Statement, Error> {
+        let span = self.current_span;
         self.advance()?; // Consommer 'maimaita'
 
         // Parser la condition entre parenthèses
@@ -519,21 +773,21 @@ impl Parser {
 
         self.expect_token(Token::RightBrace)?;
 
-        Ok(Statement::While { condition, body })
+        Ok(Statement::new(StatementKind::While { condition, body }, span))
     }
 
     /// Parse une boucle for: ga variable cikin iterable { corps }
     fn parse_for_statement(&mut self) -> Result<Statement, Error> {
+        let span = self.current_span;
         self.advance()?; // Consommer 'ga'
 
-        // Parser le nom de la variable
-        let variable = match &self.current_token {
-            Token::Identifier(name) => {
-                let var_name = name.clone();
-                self.advance()?;
-                var_name
-            }
-            _ => return Err(Error::unexpected_token("identifier", &format!("{:?}", self.current_token))),
+        // Parser le nom de la variable (ou la paire position, élément)
+        let first = self.parse_name()?;
+        let (index, variable) = if self.current_token == Token::Comma {
+            self.advance()?;
+            (Some(first), self.parse_name()?)
+        } else {
+            (None, first)
         };
 
         // Attendre 'cikin'
@@ -569,22 +823,220 @@ impl Parser {
 
         self.expect_token(Token::RightBrace)?;
 
-        Ok(Statement::For { variable, iterable, body })
+        Ok(Statement::new(StatementKind::For { index, variable, iterable, body }, span))
+    }
+
+    /// Parse un nom de variable, de fonction ou de variable de boucle
+    ///
+    /// Un mot réservé à la place du nom (`var da = 1`) est signalé par son nom.
+    fn parse_name(&mut self) -> Result<String, Error> {
+        if let Some(word) = reserved_name(&self.current_token) {
+            return Err(Error::reserved_word(word).with_span(self.current_span));
+        }
+        match &self.current_token {
+            Token::Identifier(name) => {
+                let var_name = name.clone();
+                self.advance()?;
+                Ok(var_name)
+            }
+            _ => Err(Error::unexpected_token("identifier", &format!("{:?}", self.current_token))),
+        }
     }
     
+    /// Parse un bloc gwada: gwada { } kama kuskure { } a_ƙarshe { }
+    ///
+    /// Il faut au moins un bloc kama ou a_ƙarshe; le nom après kama est facultatif.
+    fn parse_try_statement(&mut self) -> Result<Statement, Error> {
+        let span = self.current_span;
+        self.advance()?; // Consommer 'gwada'
+
+        let body = self.parse_block()?;
+
+        let (catch_variable, catch_body) = if self.current_token == Token::Catch {
+            self.advance()?; // Consommer 'kama'
+            if let Some(word) = reserved_name(&self.current_token) {
+                return Err(Error::reserved_word(word).with_span(self.current_span));
+            }
+            let variable = match &self.current_token {
+                Token::Identifier(name) => {
+                    let name = name.clone();
+                    self.advance()?;
+                    Some(name)
+                }
+                _ => None,
+            };
+            (variable, Some(self.parse_block()?))
+        } else {
+            (None, None)
+        };
+
+        let finally_body = if self.current_token == Token::Finally {
+            self.advance()?; // Consommer 'a_ƙarshe'
+            Some(self.parse_block()?)
+        } else {
+            None
+        };
+
+        if catch_body.is_none() && finally_body.is_none() {
+            return Err(Error::unexpected_token("Catch", &format!("{:?}", self.current_token)));
+        }
+
+        Ok(Statement::new(StatementKind::Try { body, catch_variable, catch_body, finally_body }, span))
+    }
+
+    /// Parse une assertion: tabbatar condition [, "saƙo"]
+    fn parse_assert_statement(&mut self) -> Result<Statement, Error> {
+        let span = self.current_span;
+        self.advance()?; // Consommer 'tabbatar'
+
+        let condition = self.parse_expression()?;
+        let message = if self.current_token == Token::Comma {
+            self.advance()?; // Consommer ','
+            Some(self.parse_expression()?)
+        } else {
+            None
+        };
+
+        Ok(Statement::new(StatementKind::Assert { condition, message }, span))
+    }
+
+    /// Parse un bloc de test: gwaji "suna" { corps }
+    ///
+    /// Les tests ne s'imbriquent pas : un bloc gwaji n'est permis qu'au premier niveau.
+    fn parse_test_block(&mut self) -> Result<Statement, Error> {
+        let span = self.current_span;
+        if self.depth > 1 {
+            return Err(Error::nested_test_block().with_span(span));
+        }
+        self.advance()?; // Consommer 'gwaji'
+
+        let name = match &self.current_token {
+            Token::String(name) => name.clone(),
+            other => {
+                return Err(Error::unexpected_token("String", &format!("{:?}", other)));
+            }
+        };
+        self.advance()?;
+
+        let body = self.parse_block()?;
+        Ok(Statement::new(StatementKind::Test { name, body }, span))
+    }
+
+    /// Parse un bloc de statements entre accolades: { ... }
+    fn parse_block(&mut self) -> Result<Vec<Statement>, Error> {
+        self.expect_token(Token::LeftBrace)?;
+
+        let mut statements = Vec::new();
+        while self.current_token != Token::RightBrace && self.current_token != Token::Eof {
+            if self.current_token == Token::Newline {
+                self.advance()?;
+                continue;
+            }
+            statements.push(self.parse_statement()?);
+        }
+
+        self.expect_token(Token::RightBrace)?;
+        Ok(statements)
+    }
+
+    /// Analyse un niveau imbriqué en refusant de dépasser `MAX_NESTING_DEPTH`
+    fn nested<T>(&mut self, parse: impl FnOnce(&mut Self) -> Result<T, Error>) -> Result<T, Error> {
+        if self.depth >= MAX_NESTING_DEPTH {
+            return Err(Error::nesting_too_deep(MAX_NESTING_DEPTH).with_span(self.current_span));
+        }
+
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
+        result
+    }
+    
+    /// Compte un maillon de plus dans une chaîne d'opérateurs (a + b + c, a.b().c())
+    ///
+    /// Une chaîne plate construit un arbre penché à gauche aussi profond qu'une
+    /// imbrication: chaque maillon consomme un niveau jusqu'à la fin de la chaîne,
+    /// et l'appelant rend les niveaux empruntés une fois la chaîne terminée.
+    fn chain_link(&mut self) -> Result<(), Error> {
+        if self.depth >= MAX_NESTING_DEPTH {
+            return Err(Error::nesting_too_deep(MAX_NESTING_DEPTH).with_span(self.current_span));
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
     /// Parse une expression
     fn parse_expression(&mut self) -> Result<Expression, Error> {
+        self.parse_or_expression()
+    }
+
+    /// Parse un ou logique: a ko b, a || b
+    fn parse_or_expression(&mut self) -> Result<Expression, Error> {
+        let depth = self.depth;
+        let mut left = self.parse_and_expression()?;
+
+        while self.current_token == Token::Or {
+            self.chain_link()?;
+            let op_span = self.current_span;
+            self.advance()?;
+            let right = self.parse_and_expression()?;
+            left = Expression::new(ExpressionKind::BinaryOp {
+                left: Box::new(left),
+                operator: BinaryOperator::Or,
+                right: Box::new(right),
+            }, op_span);
+        }
+
+        self.depth = depth;
+        Ok(left)
+    }
+
+    /// Parse un et logique: a da b, a && b
+    fn parse_and_expression(&mut self) -> Result<Expression, Error> {
+        let depth = self.depth;
+        let mut left = self.parse_not_expression()?;
+
+        while self.current_token == Token::And {
+            self.chain_link()?;
+            let op_span = self.current_span;
+            self.advance()?;
+            let right = self.parse_not_expression()?;
+            left = Expression::new(ExpressionKind::BinaryOp {
+                left: Box::new(left),
+                operator: BinaryOperator::And,
+                right: Box::new(right),
+            }, op_span);
+        }
+
+        self.depth = depth;
+        Ok(left)
+    }
+
+    /// Parse une négation logique: ba a, !a (s'applique à toute la comparaison)
+    fn parse_not_expression(&mut self) -> Result<Expression, Error> {
+        if self.current_token == Token::Not {
+            let span = self.current_span;
+            self.advance()?;
+            let operand = self.nested(Self::parse_not_expression)?;
+            return Ok(Expression::new(ExpressionKind::UnaryOp {
+                operator: UnaryOperator::Not,
+                operand: Box::new(operand),
+            }, span));
+        }
+
         self.parse_comparison_expression()
     }
     
     /// Parse une expression de comparaison (==, !=, <, >, <=, >=)
     fn parse_comparison_expression(&mut self) -> Result<Expression, Error> {
-        let mut left = self.parse_additive_expression()?;
+        let depth = self.depth;
+        let mut left = self.parse_range_expression()?;
         
         while matches!(self.current_token, 
             Token::Equal | Token::NotEqual | Token::Less | 
             Token::Greater | Token::LessEqual | Token::GreaterEqual
         ) {
+            self.chain_link()?;
+            let op_span = self.current_span;
             let operator = match self.current_token {
                 Token::Equal => {
                     self.advance()?;
@@ -613,23 +1065,56 @@ impl Parser {
                 _ => unreachable!(),
             };
             
-            let right = self.parse_additive_expression()?;
+            let right = self.parse_range_expression()?;
             
-            left = Expression::BinaryOp {
+            left = Expression::new(ExpressionKind::BinaryOp {
                 left: Box::new(left),
                 operator,
                 right: Box::new(right),
-            };
+            }, op_span);
         }
-        
+
+        self.depth = depth;
         Ok(left)
     }
     
+    /// Parse un zango: début..fin, début..=fin, avec un pas optionnel (taki)
+    fn parse_range_expression(&mut self) -> Result<Expression, Error> {
+        let start = self.parse_additive_expression()?;
+
+        let inclusive = match self.current_token {
+            Token::DotDot => false,
+            Token::DotDotEqual => true,
+            _ => return Ok(start),
+        };
+        let span = self.current_span;
+        self.advance()?;
+
+        let end = self.parse_additive_expression()?;
+
+        let step = if self.current_token == Token::Step {
+            self.advance()?;
+            Some(Box::new(self.parse_additive_expression()?))
+        } else {
+            None
+        };
+
+        Ok(Expression::new(ExpressionKind::Range {
+            start: Box::new(start),
+            end: Box::new(end),
+            inclusive,
+            step,
+        }, span))
+    }
+
     /// Parse une expression additive (gère +, -, *, /)
     fn parse_additive_expression(&mut self) -> Result<Expression, Error> {
+        let depth = self.depth;
         let mut left = self.parse_multiplicative_expression()?;
         
         while matches!(self.current_token, Token::Plus | Token::Minus) {
+            self.chain_link()?;
+            let op_span = self.current_span;
             let operator = match self.current_token {
                 Token::Plus => {
                     self.advance()?;
@@ -644,47 +1129,70 @@ impl Parser {
             
             let right = self.parse_multiplicative_expression()?;
             
-            left = Expression::BinaryOp {
+            left = Expression::new(ExpressionKind::BinaryOp {
                 left: Box::new(left),
                 operator,
                 right: Box::new(right),
-            };
+            }, op_span);
         }
-        
+
+        self.depth = depth;
         Ok(left)
     }
     
-    /// Parse une expression multiplicative (gère * et /)
+    /// Parse une expression multiplicative (gère *, /, % et //)
     fn parse_multiplicative_expression(&mut self) -> Result<Expression, Error> {
-        let mut left = self.parse_primary_expression()?;
+        let depth = self.depth;
+        let mut left = self.parse_power_expression()?;
         
-        while matches!(self.current_token, Token::Multiply | Token::Divide) {
+        while matches!(self.current_token, Token::Multiply | Token::Divide | Token::Modulo | Token::FloorDivide) {
+            self.chain_link()?;
+            let op_span = self.current_span;
             let operator = match self.current_token {
-                Token::Multiply => {
-                    self.advance()?;
-                    BinaryOperator::Multiply
-                }
-                Token::Divide => {
-                    self.advance()?;
-                    BinaryOperator::Divide
-                }
+                Token::Multiply => BinaryOperator::Multiply,
+                Token::Divide => BinaryOperator::Divide,
+                Token::Modulo => BinaryOperator::Modulo,
+                Token::FloorDivide => BinaryOperator::FloorDivide,
                 _ => unreachable!(),
             };
+            self.advance()?;
             
-            let right = self.parse_primary_expression()?;
+            let right = self.parse_power_expression()?;
             
-            left = Expression::BinaryOp {
+            left = Expression::new(ExpressionKind::BinaryOp {
                 left: Box::new(left),
                 operator,
                 right: Box::new(right),
-            };
+            }, op_span);
         }
-        
+
+        self.depth = depth;
         Ok(left)
     }
+
+    /// Parse une puissance (**), associative à droite: 2 ** 3 ** 2 = 2 ** 9
+    fn parse_power_expression(&mut self) -> Result<Expression, Error> {
+        let base = self.nested(Self::parse_primary_expression)?;
+
+        if self.current_token != Token::Power {
+            return Ok(base);
+        }
+
+        let op_span = self.current_span;
+        self.advance()?;
+        let exponent = self.nested(Self::parse_power_expression)?;
+
+        Ok(Expression::new(ExpressionKind::BinaryOp {
+            left: Box::new(base),
+            operator: BinaryOperator::Power,
+            right: Box::new(exponent),
+        }, op_span))
+    }
     
     /// Parse une expression primaire (littéraux, identificateurs, appels de fonction)
     fn parse_primary_expression(&mut self) -> Result<Expression, Error> {
+        let span = self.current_span;
+
         // Handle unary operators first
         let mut expr = match &self.current_token.clone() {
             Token::Minus | Token::Plus => {
@@ -692,54 +1200,78 @@ impl Parser {
                     Token::Minus => UnaryOperator::Negate,
                     Token::Plus => return {
                         self.advance()?;
-                        self.parse_primary_expression()
+                        self.nested(Self::parse_primary_expression)
                     },
                     _ => unreachable!(),
                 };
                 self.advance()?;
-                let operand = self.parse_primary_expression()?;
-                Expression::UnaryOp {
+                // La puissance lie plus fort que le moins unaire: -2 ** 2 = -(2 ** 2)
+                let operand = self.parse_power_expression()?;
+                Expression::new(ExpressionKind::UnaryOp {
                     operator: op,
                     operand: Box::new(operand),
-                }
+                }, span)
+            }
+            Token::Not => {
+                self.advance()?;
+                let operand = self.nested(Self::parse_primary_expression)?;
+                Expression::new(ExpressionKind::UnaryOp {
+                    operator: UnaryOperator::Not,
+                    operand: Box::new(operand),
+                }, span)
             }
             Token::Number(n) => {
                 let value = *n;
                 self.advance()?;
-                Expression::Number(value)
+                Expression::new(ExpressionKind::Number(value), span)
             }
 
             Token::Float(f) => {
                 let value = *f;
                 self.advance()?;
-                Expression::Float(value)
+                Expression::new(ExpressionKind::Float(value), span)
             }
 
             Token::String(s) => {
                 let value = s.clone();
                 self.advance()?;
-                Expression::String(value)
+                Expression::new(ExpressionKind::String(value), span)
             }
 
             Token::True => {
                 self.advance()?;
-                Expression::Boolean(true)
+                Expression::new(ExpressionKind::Boolean(true), span)
             }
 
             Token::False => {
                 self.advance()?;
-                Expression::Boolean(false)
+                Expression::new(ExpressionKind::Boolean(false), span)
             }
 
             Token::Input => {
                 self.advance()?;
-                Expression::Input
+
+                // Invite entre parenthèses sur la même ligne, comme pour un appel
+                let mut prompt = None;
+                if self.current_token == Token::LeftParen && self.current_span.line == span.line {
+                    self.advance()?;
+                    if self.current_token != Token::RightParen {
+                        prompt = Some(Box::new(self.parse_expression()?));
+                    }
+                    self.expect_token(Token::RightParen)?;
+                }
+
+                Expression::new(ExpressionKind::Input(prompt), span)
             }
 
             Token::LeftBracket => {
                 return self.parse_list_expression();
             }
 
+            Token::LeftBrace => {
+                return self.parse_map_expression();
+            }
+
             Token::LeftParen => {
                 self.advance()?; // Consommer '('
                 let inner_expr = self.parse_expression()?;
@@ -747,6 +1279,8 @@ impl Parser {
                 inner_expr
             }
 
+            Token::Function => self.parse_lambda_expression()?,
+
             Token::Identifier(name) => {
                 let var_name = name.clone();
                 self.advance()?;
@@ -756,32 +1290,22 @@ impl Parser {
                 if self.current_token == Token::LeftParen {
                     self.advance()?; // Consommer '('
 
-                    let mut arguments = Vec::new();
-
                     // Parse les arguments
-                    while self.current_token != Token::RightParen {
-                        let arg = self.parse_comparison_expression()?;
-                        arguments.push(arg);
+                    let arguments = self.parse_call_arguments()?;
 
-                        if self.current_token == Token::Comma {
-                            self.advance()?;
-                        }
-                    }
-
-                    self.expect_token(Token::RightParen)?;
-
-                    Expression::FunctionCall {
+                    Expression::new(ExpressionKind::FunctionCall {
                         name: var_name,
                         arguments,
-                    }
+                    }, span)
                 }
                 // Syntaxe sans parenthèses : fonction arg1 arg2 (style Ruby/Python)
-                else if self.is_function_call_without_parens() {
+                else if self.is_function_call_without_parens() && self.current_span.line == span.line {
                     let mut arguments = Vec::new();
 
                     // Parse les arguments jusqu'à un délimiteur ou fin de ligne
-                    while !self.is_end_of_expression() {
-                        let arg = self.parse_primary_expression()?;
+                    // (les newlines sont retirés, on compare donc les lignes)
+                    while !self.is_end_of_expression() && self.current_span.line == span.line {
+                        let arg = self.nested(Self::parse_primary_expression)?;
                         arguments.push(arg);
 
                         // Les arguments sont séparés par des espaces ou virgules
@@ -790,36 +1314,54 @@ impl Parser {
                         }
                     }
 
-                    Expression::FunctionCall {
+                    Expression::new(ExpressionKind::FunctionCall {
                         name: var_name,
                         arguments,
-                    }
+                    }, span)
                 } else {
-                    Expression::Identifier(var_name)
+                    Expression::new(ExpressionKind::Identifier(var_name), span)
                 }
             }
 
             _ => return Err(Error::expected_expression()),
         };
 
-        // Gérer l'indexation et les appels de méthode
+        // Gérer l'indexation, les appels de méthode et les appels de valeurs
+        let depth = self.depth;
         loop {
             match &self.current_token {
+                // f(1)(2) ou ayyuka[0](x): seulement sur la même ligne, pour ne pas
+                // confondre avec une expression entre parenthèses à la ligne suivante
+                Token::LeftParen if self.current_span.line == expr.span.line && Self::can_be_called(&expr) => {
+                    self.chain_link()?;
+                    let call_span = self.current_span;
+                    self.advance()?; // Consommer '('
+                    let arguments = self.parse_call_arguments()?;
+
+                    expr = Expression::new(ExpressionKind::Call {
+                        callee: Box::new(expr),
+                        arguments,
+                    }, call_span);
+                }
                 Token::LeftBracket => {
                     // Indexation: expr[index]
+                    self.chain_link()?;
+                    let index_span = self.current_span;
                     self.advance()?; // Consommer '['
                     let index = self.parse_expression()?;
                     self.expect_token(Token::RightBracket)?;
 
-                    expr = Expression::Index {
+                    expr = Expression::new(ExpressionKind::Index {
                         object: Box::new(expr),
                         index: Box::new(index),
-                    };
+                    }, index_span);
                 }
                 Token::Dot => {
                     // Appel de méthode: expr.method(args)
+                    self.chain_link()?;
                     self.advance()?; // Consommer '.'
 
+                    let method_span = self.current_span;
                     let method_name = match &self.current_token {
                         Token::Identifier(name) => {
                             let method = name.clone();
@@ -832,39 +1374,57 @@ impl Parser {
                     // Vérifier s'il y a des arguments
                     let arguments = if self.current_token == Token::LeftParen {
                         self.advance()?; // Consommer '('
-
-                        let mut args = Vec::new();
-
-                        while self.current_token != Token::RightParen {
-                            let arg = self.parse_comparison_expression()?;
-                            args.push(arg);
-
-                            if self.current_token == Token::Comma {
-                                self.advance()?;
-                            }
-                        }
-
-                        self.expect_token(Token::RightParen)?;
-                        args
+                        self.parse_call_arguments()?
                     } else {
                         Vec::new()
                     };
 
-                    expr = Expression::MethodCall {
+                    expr = Expression::new(ExpressionKind::MethodCall {
                         receiver: Box::new(expr),
                         method: method_name,
                         arguments,
-                    };
+                    }, method_span);
                 }
                 _ => break,
             }
         }
 
+        self.depth = depth;
         Ok(expr)
     }
     
+    /// Vérifie si une expression peut produire une fonction (les littéraux ne le peuvent pas)
+    fn can_be_called(expression: &Expression) -> bool {
+        matches!(expression.kind,
+            ExpressionKind::Identifier(_) |
+            ExpressionKind::Index { .. } |
+            ExpressionKind::FunctionCall { .. } |
+            ExpressionKind::Call { .. } |
+            ExpressionKind::MethodCall { .. } |
+            ExpressionKind::Lambda { .. }
+        )
+    }
+
+    /// Parse les arguments d'un appel jusqu'à la parenthèse fermante (déjà après '(')
+    fn parse_call_arguments(&mut self) -> Result<Vec<Expression>, Error> {
+        let mut arguments = Vec::new();
+
+        while self.current_token != Token::RightParen {
+            let arg = self.parse_expression()?;
+            arguments.push(arg);
+
+            if self.current_token == Token::Comma {
+                self.advance()?;
+            }
+        }
+
+        self.expect_token(Token::RightParen)?;
+        Ok(arguments)
+    }
+
     /// Parse une liste: [element1, element2, ...]
     fn parse_list_expression(&mut self) -> Result<Expression, Error> {
+        let span = self.current_span;
         self.advance()?; // Consommer '['
         
         let mut elements = Vec::new();
@@ -886,12 +1446,68 @@ impl Parser {
         
         self.expect_token(Token::RightBracket)?;
         
-        Ok(Expression::List(elements))
+        Ok(Expression::new(ExpressionKind::List(elements), span))
     }
+
+    /// Parse un dictionnaire: {clé: valeur, ...}
+    fn parse_map_expression(&mut self) -> Result<Expression, Error> {
+        let span = self.current_span;
+        self.advance()?; // Consommer '{'
+
+        let mut entries = Vec::new();
+
+        // Parse les paires clé: valeur
+        while self.current_token != Token::RightBrace && self.current_token != Token::Eof {
+            let key = self.parse_expression()?;
+            self.expect_token(Token::Colon)?;
+            let value = self.parse_expression()?;
+            entries.push((key, value));
+
+            if self.current_token == Token::Comma {
+                self.advance()?;
+            } else if self.current_token != Token::RightBrace {
+                return Err(Error::unexpected_token(
+                    ", ou }",
+                    &format!("{:?}", self.current_token)
+                ));
+            }
+        }
+
+        self.expect_token(Token::RightBrace)?;
+
+        Ok(Expression::new(ExpressionKind::Map(entries), span))
+    }
+}
+
+/// Parse des statements sans 'fara' / 'ƙare' (utilisé par le REPL)
+pub fn parse_statements(tokens: Vec<SpannedToken>) -> Result<Program, Error> {
+    let mut parser = Parser::new(tokens)?;
+    parser.parse_statements().map_err(|e| e.with_span(parser.current_span))
 }
 
 /// Fonction utilitaire pour parser des tokens
-pub fn parse(tokens: Vec<Token>) -> Result<Program, Error> {
+pub fn parse(tokens: Vec<SpannedToken>) -> Result<Program, Error> {
     let mut parser = Parser::new(tokens)?; 
-    parser.parse_program()
-}
\ No newline at end of file
+    // Une erreur de syntaxe est signalée à la position du token où le parser s'est arrêté
+    parser.parse_program().map_err(|e| e.with_span(parser.current_span))
+}
+
+/// Mot-clé que l'on pourrait prendre pour un nom de variable
+///
+/// Ce sont les mots réservés depuis l'ajout des opérateurs `da`/`ko`/`ba`, des pas
+/// `taki`, de `gwada`/`kama`/`a_ƙarshe`/`jefa` et de `tabbatar`/`gwaji`.
+fn reserved_name(token: &Token) -> Option<&'static str> {
+    match token {
+        Token::And => Some("da"),
+        Token::Or => Some("ko"),
+        Token::Not => Some("ba"),
+        Token::Step => Some("taki"),
+        Token::Try => Some("gwada"),
+        Token::Catch => Some("kama"),
+        Token::Finally => Some("a_ƙarshe"),
+        Token::Throw => Some("jefa"),
+        Token::Assert => Some("tabbatar"),
+        Token::Test => Some("gwaji"),
+        _ => None,
+    }
+}
@@ -0,0 +1,184 @@
+//! Session interactive (REPL) pour le langage Dabara
+//!
+//! Ce module garde un seul interpréteur vivant entre les lignes saisies,
+//! accepte des statements sans l'enveloppe `fara` / `ƙare`, regroupe les
+//! lignes d'un bloc `{ ... }` inachevé et gère les méta-commandes (`:vars`,
+//! `:functions`, `:reset`, ...). La lecture du terminal reste dans `main.rs`.
+
+use crate::error::{Error, RenderStyle};
+use crate::interpreter::Interpreter;
+use crate::lexer::{tokenize, Token};
+use crate::parser::parse_statements;
+
+/// Invite affichée pour une nouvelle instruction
+pub const PROMPT: &str = ">> ";
+/// Invite affichée pendant la saisie d'un bloc sur plusieurs lignes
+pub const CONTINUATION_PROMPT: &str = ".. ";
+/// Nom de « fichier » utilisé dans les diagnostics du REPL
+const REPL_FILE: &str = "<repl>";
+
+const HELP: &str = "\
+Umarni na musamman (méta-commandes):
+  :vars        Nuna masu canji (variables)
+  :functions   Nuna ayyuka (fonctions)
+  :history     Nuna abubuwan da aka rubuta a baya
+  :reset       Share komai (variables da fonctions)
+  :help        Nuna wannan taimako
+  :quit        Fita daga REPL";
+
+/// Résultat du traitement d'une ligne saisie
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReplStep {
+    /// Le bloc n'est pas terminé : il faut lire une autre ligne
+    Incomplete,
+    /// Ligne traitée, avec un texte éventuel à afficher
+    Done(Option<String>),
+    /// Erreur, avec le diagnostic déjà rendu
+    Failed(String),
+    /// L'utilisateur a demandé à quitter
+    Quit,
+}
+
+/// Session interactive Dabara
+pub struct Repl {
+    interpreter: Interpreter,
+    /// Lignes en attente d'un bloc inachevé
+    buffer: String,
+    /// Entrées complètes déjà exécutées
+    history: Vec<String>,
+    style: RenderStyle,
+}
+
+impl Repl {
+    /// Crée une nouvelle session
+    pub fn new(style: RenderStyle) -> Self {
+        Repl {
+            interpreter: Interpreter::new(),
+            buffer: String::new(),
+            history: Vec::new(),
+            style,
+        }
+    }
+
+    /// Invite à afficher avant la prochaine ligne
+    pub fn prompt(&self) -> &'static str {
+        if self.buffer.is_empty() {
+            PROMPT
+        } else {
+            CONTINUATION_PROMPT
+        }
+    }
+
+    /// Entrées déjà exécutées
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+
+    /// Interpréteur de la session
+    pub fn interpreter(&self) -> &Interpreter {
+        &self.interpreter
+    }
+
+    /// Traite une ligne saisie par l'utilisateur
+    pub fn feed_line(&mut self, line: &str) -> ReplStep {
+        let trimmed = line.trim();
+
+        if self.buffer.is_empty() {
+            if trimmed.is_empty() {
+                return ReplStep::Done(None);
+            }
+            if trimmed.starts_with(':') {
+                return self.meta_command(trimmed);
+            }
+        }
+
+        self.buffer.push_str(line);
+        self.buffer.push('\n');
+
+        if !is_complete(&self.buffer) {
+            return ReplStep::Incomplete;
+        }
+
+        let source = std::mem::take(&mut self.buffer);
+        self.history.push(source.trim_end().to_string());
+
+        match self.evaluate(&source) {
+            Ok(Some(value)) => ReplStep::Done(Some(value)),
+            Ok(None) => ReplStep::Done(None),
+            Err(error) => ReplStep::Failed(error.render(&source, REPL_FILE, self.style)),
+        }
+    }
+
+    /// Exécute une entrée complète et retourne la valeur à afficher
+    fn evaluate(&mut self, source: &str) -> Result<Option<String>, Error> {
+        let tokens = tokenize(source)?;
+        let program = parse_statements(tokens)?;
+        let value = self.interpreter.execute_interactive(program)?;
+
+        Ok(value.map(|v| v.to_string()))
+    }
+
+    /// Exécute une méta-commande (`:vars`, `:reset`, ...)
+    fn meta_command(&mut self, command: &str) -> ReplStep {
+        match command {
+            ":vars" => {
+                let lines: Vec<String> = self.interpreter.variable_names()
+                    .into_iter()
+                    .filter_map(|name| {
+                        self.interpreter.get_variable(&name)
+                            .map(|value| format!("{} = {} ({})", name, value, value.type_name()))
+                    })
+                    .collect();
+                ReplStep::Done(Some(lines.join("\n")))
+            }
+            ":functions" => {
+                let mut names: Vec<&String> = self.interpreter.functions.keys().collect();
+                names.sort();
+                let lines: Vec<String> = names.into_iter()
+                    .map(|name| {
+                        let function = &self.interpreter.functions[name];
+                        format!("aiki {}({})", name, function.parameters.join(", "))
+                    })
+                    .collect();
+                if lines.is_empty() {
+                    ReplStep::Done(Some("Babu aiki da aka rubuta tukuna".to_string()))
+                } else {
+                    ReplStep::Done(Some(lines.join("\n")))
+                }
+            }
+            ":history" => ReplStep::Done(Some(self.history.join("\n"))),
+            ":reset" => {
+                self.interpreter.clear_variables();
+                ReplStep::Done(Some("An share komai".to_string()))
+            }
+            ":help" | ":taimako" => ReplStep::Done(Some(HELP.to_string())),
+            ":quit" | ":fita" => ReplStep::Quit,
+            _ => ReplStep::Failed(format!(
+                "Ba a gane umarnin '{}' ba. Rubuta :help don taimako",
+                command
+            )),
+        }
+    }
+}
+
+/// Vérifie si une entrée est complète : tous les `{`, `(` et `[` sont fermés
+///
+/// Une entrée qui ne se tokenise pas est considérée complète pour que
+/// l'erreur soit signalée tout de suite.
+fn is_complete(source: &str) -> bool {
+    let tokens = match tokenize(source) {
+        Ok(tokens) => tokens,
+        Err(_) => return true,
+    };
+
+    let mut depth: i64 = 0;
+    for spanned in &tokens {
+        match spanned.token {
+            Token::LeftBrace | Token::LeftParen | Token::LeftBracket => depth += 1,
+            Token::RightBrace | Token::RightParen | Token::RightBracket => depth -= 1,
+            _ => {}
+        }
+    }
+
+    depth <= 0
+}
@@ -2,31 +2,18 @@
 //! 
 //! Provides functions to convert between different data types
 
-use crate::interpreter::{Interpreter, Value, Function};
+use crate::interpreter::{graphemes, Arity, Interpreter, Value};
+use crate::decimal::Decimal;
 use crate::error::Error;
 
 /// Register all type conversion functions with the interpreter
 pub fn register_conversion_functions(interpreter: &mut Interpreter) -> Result<(), Error> {
-    register_function(interpreter, "int", int)?;
-    register_function(interpreter, "float", float)?;
-    register_function(interpreter, "string", string)?;
-    register_function(interpreter, "list", list)?;
-    register_function(interpreter, "bool", bool_fn)?;
-    
-    Ok(())
-}
-
-/// Register a single function with the interpreter
-fn register_function(interpreter: &mut Interpreter, name: &str, func: fn(&[Value]) -> Result<Value, Error>) -> Result<(), Error> {
-    let function = Function {
-        parameters: vec![], // Will be checked at runtime
-        body: vec![], // Native implementation
-    };
-    
-    interpreter.functions.insert(name.to_string(), function);
-    
-    // TODO: Store native function pointers separately
-    // This is a placeholder - we need to extend the interpreter to support native functions
+    interpreter.register_native("int", Arity::Exact(1), int);
+    interpreter.register_native("float", Arity::Exact(1), float);
+    interpreter.register_native("string", Arity::Exact(1), string);
+    interpreter.register_native("list", Arity::Exact(1), list);
+    interpreter.register_native("bool", Arity::Exact(1), bool_fn);
+    interpreter.register_native("kudi", Arity::Exact(1), kudi);
     
     Ok(())
 }
@@ -40,6 +27,9 @@ fn int(args: &[Value]) -> Result<Value, Error> {
     match &args[0] {
         Value::Number(n) => Ok(Value::Number(*n)),
         Value::Float(f) => Ok(Value::Number(*f as i64)),
+        Value::Decimal(d) => d.trunc_to_i64()
+            .map(Value::Number)
+            .ok_or_else(|| Error::integer_overflow("int")),
         Value::String(s) => {
             match s.trim().parse::<i64>() {
                 Ok(n) => Ok(Value::Number(n)),
@@ -48,6 +38,10 @@ fn int(args: &[Value]) -> Result<Value, Error> {
         }
         Value::Boolean(b) => Ok(Value::Number(if *b { 1 } else { 0 })),
         Value::List(_) => Err(Error::runtime_error("ba za a iya canza jeri zuwa lambar (cannot convert list to integer)")),
+        Value::Map(_) => Err(Error::runtime_error("ba za a iya canza ƙamus zuwa lambar (cannot convert map to integer)")),
+        Value::Range(_) => Err(Error::runtime_error("ba za a iya canza zango zuwa lambar (cannot convert range to integer)")),
+        Value::Function(_) | Value::NativeFunction { .. } => Err(Error::runtime_error("ba za a iya canza aiki zuwa lambar (cannot convert function to integer)")),
+        Value::Error(_) => Err(Error::runtime_error("ba za a iya canza kuskure zuwa lambar (cannot convert error to integer)")),
     }
 }
 
@@ -60,6 +54,7 @@ fn float(args: &[Value]) -> Result<Value, Error> {
     match &args[0] {
         Value::Number(n) => Ok(Value::Float(*n as f64)),
         Value::Float(f) => Ok(Value::Float(*f)),
+        Value::Decimal(d) => Ok(Value::Float(d.to_f64())),
         Value::String(s) => {
             match s.trim().parse::<f64>() {
                 Ok(f) => Ok(Value::Float(f)),
@@ -68,6 +63,10 @@ fn float(args: &[Value]) -> Result<Value, Error> {
         }
         Value::Boolean(b) => Ok(Value::Float(if *b { 1.0 } else { 0.0 })),
         Value::List(_) => Err(Error::runtime_error("ba za a iya canza jeri zuwa float (cannot convert list to float)")),
+        Value::Map(_) => Err(Error::runtime_error("ba za a iya canza ƙamus zuwa float (cannot convert map to float)")),
+        Value::Range(_) => Err(Error::runtime_error("ba za a iya canza zango zuwa float (cannot convert range to float)")),
+        Value::Function(_) | Value::NativeFunction { .. } => Err(Error::runtime_error("ba za a iya canza aiki zuwa float (cannot convert function to float)")),
+        Value::Error(_) => Err(Error::runtime_error("ba za a iya canza kuskure zuwa float (cannot convert error to float)")),
     }
 }
 
@@ -80,20 +79,38 @@ fn string(args: &[Value]) -> Result<Value, Error> {
     match &args[0] {
         Value::Number(n) => Ok(Value::String(n.to_string())),
         Value::Float(f) => Ok(Value::String(f.to_string())),
+        Value::Decimal(d) => Ok(Value::String(d.to_string())),
         Value::String(s) => Ok(Value::String(s.clone())),
         Value::Boolean(b) => Ok(Value::String(if *b { "gaskiya".to_string() } else { "karya".to_string() })),
         Value::List(elements) => {
-            let strings: Vec<String> = elements.iter()
-                .map(|v| match v {
-                    Value::Number(n) => n.to_string(),
-                    Value::Float(f) => f.to_string(),
-                    Value::String(s) => format!("\"{}\"", s),
-                    Value::Boolean(b) => if *b { "gaskiya".to_string() } else { "karya".to_string() },
-                    Value::List(_) => "[...]".to_string(),
-                })
+            let strings: Vec<String> = elements.borrow().iter()
+                .map(element_repr)
                 .collect();
             Ok(Value::String(format!("[{}]", strings.join(", "))))
         }
+        Value::Map(map) => {
+            let strings: Vec<String> = map.borrow().iter()
+                .map(|(k, v)| format!("{}: {}", element_repr(k), element_repr(v)))
+                .collect();
+            Ok(Value::String(format!("{{{}}}", strings.join(", "))))
+        }
+        Value::Range(range) => Ok(Value::String(range.to_string())),
+        Value::Function(_) | Value::NativeFunction { .. } | Value::Error(_) => Ok(Value::String(args[0].to_string())),
+    }
+}
+
+/// Representation of a value nested inside a list or map (strings are quoted)
+fn element_repr(value: &Value) -> String {
+    match value {
+        Value::Number(n) => n.to_string(),
+        Value::Float(f) => f.to_string(),
+        Value::Decimal(d) => d.to_string(),
+        Value::String(s) => format!("\"{}\"", s),
+        Value::Boolean(b) => if *b { "gaskiya".to_string() } else { "karya".to_string() },
+        Value::List(_) => "[...]".to_string(),
+        Value::Map(_) => "{...}".to_string(),
+        Value::Range(range) => range.to_string(),
+        Value::Function(_) | Value::NativeFunction { .. } | Value::Error(_) => value.to_string(),
     }
 }
 
@@ -104,13 +121,9 @@ fn list(args: &[Value]) -> Result<Value, Error> {
     }
     
     match &args[0] {
-        Value::List(elements) => Ok(Value::List(elements.clone())),
-        Value::String(s) => {
-            let chars: Vec<Value> = s.chars()
-                .map(|c| Value::String(c.to_string()))
-                .collect();
-            Ok(Value::List(chars))
-        }
+        Value::List(elements) => Ok(Value::list(elements.borrow().clone())),
+        // A string splits into visible letters, like indexing and ga ... cikin
+        Value::String(s) => Ok(Value::list(graphemes(s).into_iter().map(Value::String).collect())),
         Value::Number(n) => {
             // Convert number to its digits
             let digits: Vec<Value> = n.to_string()
@@ -118,7 +131,7 @@ fn list(args: &[Value]) -> Result<Value, Error> {
                 .filter(|c| c.is_ascii_digit())
                 .map(|c| Value::String(c.to_string()))
                 .collect();
-            Ok(Value::List(digits))
+            Ok(Value::list(digits))
         }
         Value::Float(f) => {
             // Convert float to its characters
@@ -127,9 +140,23 @@ fn list(args: &[Value]) -> Result<Value, Error> {
                 .filter(|c| c.is_ascii_digit() || *c == '.' || *c == '-')
                 .map(|c| Value::String(c.to_string()))
                 .collect();
-            Ok(Value::List(chars))
+            Ok(Value::list(chars))
         }
-        Value::Boolean(b) => Ok(Value::List(vec![Value::Boolean(*b)])),
+        Value::Decimal(d) => {
+            // Convert decimal to its characters (without thousands separators)
+            let chars: Vec<Value> = d.to_plain_string()
+                .chars()
+                .map(|c| Value::String(c.to_string()))
+                .collect();
+            Ok(Value::list(chars))
+        }
+        Value::Boolean(b) => Ok(Value::list(vec![Value::Boolean(*b)])),
+        // A map converts to the list of its keys
+        Value::Map(map) => Ok(Value::list(map.borrow().keys().cloned().collect())),
+        // A range materializes all of its numbers
+        Value::Range(range) => Ok(Value::list(range.iter().map(Value::Number).collect())),
+        Value::Function(_) | Value::NativeFunction { .. } => Err(Error::runtime_error("ba za a iya canza aiki zuwa jeri (cannot convert function to list)")),
+        Value::Error(_) => Err(Error::runtime_error("ba za a iya canza kuskure zuwa jeri (cannot convert error to list)")),
     }
 }
 
@@ -143,7 +170,32 @@ fn bool_fn(args: &[Value]) -> Result<Value, Error> {
         Value::Boolean(b) => Ok(Value::Boolean(*b)),
         Value::Number(n) => Ok(Value::Boolean(*n != 0)),
         Value::Float(f) => Ok(Value::Boolean(*f != 0.0 && !f.is_nan())),
+        Value::Decimal(d) => Ok(Value::Boolean(!d.is_zero())),
         Value::String(s) => Ok(Value::Boolean(!s.trim().is_empty() && s.trim() != "karya")),
-        Value::List(elements) => Ok(Value::Boolean(!elements.is_empty())),
+        Value::List(elements) => Ok(Value::Boolean(!elements.borrow().is_empty())),
+        Value::Map(map) => Ok(Value::Boolean(!map.borrow().is_empty())),
+        Value::Range(range) => Ok(Value::Boolean(!range.is_empty())),
+        Value::Function(_) | Value::NativeFunction { .. } | Value::Error(_) => Ok(Value::Boolean(true)),
+    }
+}
+
+/// Convert to an exact decimal: kudi(abu) → kuɗi
+///
+/// Accepts integers, floats (through their shortest writing, so `kudi(0.1)` is
+/// exactly 0.1) and strings such as "1,250.50".
+fn kudi(args: &[Value]) -> Result<Value, Error> {
+    if args.len() != 1 {
+        return Err(Error::runtime_error("kudi yana bukata daya kawai (kudi requires exactly one argument)"));
+    }
+    
+    match &args[0] {
+        Value::Number(n) => Ok(Value::Decimal(Decimal::from_int(*n))),
+        Value::Float(f) => Ok(Value::Decimal(Decimal::from_f64(*f)?)),
+        Value::Decimal(d) => Ok(Value::Decimal(*d)),
+        Value::String(s) => Ok(Value::Decimal(Decimal::parse(s)?)),
+        other => Err(Error::runtime_error(&format!(
+            "ba za a iya canza {} zuwa kuɗi (cannot convert to decimal)",
+            other.type_name()
+        ))),
     }
 }
@@ -0,0 +1,73 @@
+//! Typed input functions for Dabara
+//!
+//! `karɓa` guesses the type of what the user typed, so "007" becomes the number 7.
+//! These functions ask for one type and prompt again, in Hausa, until the answer fits.
+
+use crate::interpreter::{Arity, HostFn, Interpreter, Value, DEFAULT_INPUT_PROMPT};
+use crate::error::Error;
+
+/// Register all typed input functions with the interpreter
+pub fn register_input_functions(interpreter: &mut Interpreter) -> Result<(), Error> {
+    let functions: [(&str, &str, HostFn); 3] = [
+        ("karɓa_lamba", "karba_lamba", read_number),
+        ("karɓa_jimla", "karba_jimla", read_text),
+        ("karɓa_ee", "karba_ee", read_yes_no),
+    ];
+
+    // Each function also answers to its spelling without the hooked ɓ
+    for (name, latin_name, function) in functions {
+        interpreter.register_host_native(name, Arity::Range(0, 1), function);
+        interpreter.register_host_native(latin_name, Arity::Range(0, 1), function);
+    }
+
+    Ok(())
+}
+
+/// Read a number: karɓa_lamba("Shekarunka? ") → lambar
+///
+/// Whole numbers stay integers; anything else that parses is a float.
+fn read_number(interpreter: &mut Interpreter, args: &[Value]) -> Result<Value, Error> {
+    let prompt = prompt_argument(args);
+
+    loop {
+        let answer = interpreter.read_input(&prompt)?;
+        let answer = answer.trim();
+
+        if let Ok(number) = answer.parse::<i64>() {
+            return Ok(Value::Number(number));
+        }
+        match answer.parse::<f64>() {
+            Ok(number) if number.is_finite() => return Ok(Value::Float(number)),
+            _ => interpreter.write_output("Wannan ba lamba ba ce, sake rubutawa (not a number, try again)\n"),
+        }
+    }
+}
+
+/// Read text exactly as typed: karɓa_jimla("Lambar wayarka? ") → jimla
+fn read_text(interpreter: &mut Interpreter, args: &[Value]) -> Result<Value, Error> {
+    let prompt = prompt_argument(args);
+    Ok(Value::String(interpreter.read_input(&prompt)?))
+}
+
+/// Read a yes/no answer: karɓa_ee("Ka gama? ") → gaskiya ko karya
+///
+/// Accepts ee / a'a as well as yes / no, oui / non and gaskiya / karya.
+fn read_yes_no(interpreter: &mut Interpreter, args: &[Value]) -> Result<Value, Error> {
+    let prompt = prompt_argument(args);
+
+    loop {
+        let answer = interpreter.read_input(&prompt)?;
+        let answer = answer.trim().to_lowercase().replace('’', "'");
+
+        match answer.as_str() {
+            "ee" | "eh" | "i" | "yes" | "y" | "oui" | "gaskiya" => return Ok(Value::Boolean(true)),
+            "a'a" | "aa" | "a" | "no" | "n" | "non" | "karya" => return Ok(Value::Boolean(false)),
+            _ => interpreter.write_output("Amsa da 'ee' ko 'a'a' (answer yes or no)\n"),
+        }
+    }
+}
+
+/// The optional prompt shared by all input functions (the registry allows at most one)
+fn prompt_argument(args: &[Value]) -> String {
+    args.first().map_or_else(|| DEFAULT_INPUT_PROMPT.to_string(), Value::to_string)
+}
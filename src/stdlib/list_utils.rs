@@ -2,31 +2,21 @@
 //! 
 //! Provides functions for list manipulation and analysis
 
-use crate::interpreter::{Interpreter, Value, Function};
+use std::cmp::Ordering;
+
+use crate::interpreter::{Arity, Interpreter, Value};
+use crate::decimal::Decimal;
 use crate::error::Error;
 
 /// Register all list utility functions with the interpreter
 pub fn register_list_functions(interpreter: &mut Interpreter) -> Result<(), Error> {
-    register_function(interpreter, "sort", sort)?;
-    register_function(interpreter, "reverse", reverse)?;
-    register_function(interpreter, "filter", filter)?;
-    register_function(interpreter, "map", map)?;
-    register_function(interpreter, "sum", sum)?;
-    
-    Ok(())
-}
-
-/// Register a single function with the interpreter
-fn register_function(interpreter: &mut Interpreter, name: &str, func: fn(&[Value]) -> Result<Value, Error>) -> Result<(), Error> {
-    let function = Function {
-        parameters: vec![], // Will be checked at runtime
-        body: vec![], // Native implementation
-    };
-    
-    interpreter.functions.insert(name.to_string(), function);
-    
-    // TODO: Store native function pointers separately
-    // This is a placeholder - we need to extend the interpreter to support native functions
+    interpreter.register_native("sort", Arity::Exact(1), sort);
+    interpreter.register_native("reverse", Arity::Exact(1), reverse);
+    interpreter.register_higher_order("filter", Arity::Exact(2), filter);
+    interpreter.register_higher_order("map", Arity::Exact(2), map);
+    interpreter.register_higher_order("reduce", Arity::Range(2, 3), reduce);
+    interpreter.register_higher_order("sort_by", Arity::Exact(2), sort_by);
+    interpreter.register_native("sum", Arity::Exact(1), sum);
     
     Ok(())
 }
@@ -39,33 +29,62 @@ fn sort(args: &[Value]) -> Result<Value, Error> {
     
     match &args[0] {
         Value::List(elements) => {
-            let mut sorted = elements.clone();
-            
-            // Simple sort for mixed types - strings first, then numbers, then booleans
-            sorted.sort_by(|a, b| {
-                match (a, b) {
-                    (Value::String(a), Value::String(b)) => a.cmp(b),
-                    (Value::String(_), _) => std::cmp::Ordering::Less,
-                    (_, Value::String(_)) => std::cmp::Ordering::Greater,
-                    (Value::Number(a), Value::Number(b)) => a.cmp(b),
-                    (Value::Number(_), _) => std::cmp::Ordering::Less,
-                    (_, Value::Number(_)) => std::cmp::Ordering::Greater,
-                    (Value::Float(a), Value::Float(b)) => a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal),
-                    (Value::Float(_), _) => std::cmp::Ordering::Less,
-                    (_, Value::Float(_)) => std::cmp::Ordering::Greater,
-                    (Value::Boolean(a), Value::Boolean(b)) => a.cmp(b),
-                    (Value::Boolean(_), Value::List(_)) => std::cmp::Ordering::Less,
-                    (Value::List(_), Value::Boolean(_)) => std::cmp::Ordering::Greater,
-                    (Value::List(_), Value::List(_)) => std::cmp::Ordering::Equal,
-                }
-            });
-            
-            Ok(Value::List(sorted))
+            let mut sorted = elements.borrow().clone();
+            sorted.sort_by(compare_values);
+            Ok(Value::list(sorted))
         }
         _ => Err(Error::runtime_error("sort yana bukata jeri (sort requires a list)")),
     }
 }
 
+/// Sort list by a key function: sort_by(jeri, aiki) → jeri
+///
+/// The key is computed once per element; elements with equal keys keep their order.
+fn sort_by(interpreter: &mut Interpreter, args: &[Value]) -> Result<Value, Error> {
+    let elements = list_argument("sort_by", &args[0])?;
+
+    let mut keyed = Vec::new();
+    for element in elements {
+        let key = interpreter.call_value(&args[1], vec![element.clone()])?;
+        keyed.push((key, element));
+    }
+
+    keyed.sort_by(|(a, _), (b, _)| compare_values(a, b));
+    Ok(Value::list(keyed.into_iter().map(|(_, element)| element).collect()))
+}
+
+/// Ordering used by sort and sort_by for mixed types:
+/// strings, numbers, floats, decimals, booleans, lists, maps, ranges, then functions
+fn compare_values(a: &Value, b: &Value) -> Ordering {
+    match (a, b) {
+        (Value::String(a), Value::String(b)) => a.cmp(b),
+        (Value::String(_), _) => Ordering::Less,
+        (_, Value::String(_)) => Ordering::Greater,
+        (Value::Number(a), Value::Number(b)) => a.cmp(b),
+        (Value::Number(_), _) => Ordering::Less,
+        (_, Value::Number(_)) => Ordering::Greater,
+        (Value::Float(a), Value::Float(b)) => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+        (Value::Float(_), _) => Ordering::Less,
+        (_, Value::Float(_)) => Ordering::Greater,
+        (Value::Decimal(a), Value::Decimal(b)) => a.cmp(b),
+        (Value::Decimal(_), _) => Ordering::Less,
+        (_, Value::Decimal(_)) => Ordering::Greater,
+        (Value::Boolean(a), Value::Boolean(b)) => a.cmp(b),
+        (Value::Boolean(_), _) => Ordering::Less,
+        (_, Value::Boolean(_)) => Ordering::Greater,
+        (Value::List(_), Value::List(_)) => Ordering::Equal,
+        (Value::List(_), _) => Ordering::Less,
+        (_, Value::List(_)) => Ordering::Greater,
+        (Value::Map(_), Value::Map(_)) => Ordering::Equal,
+        (Value::Map(_), _) => Ordering::Less,
+        (_, Value::Map(_)) => Ordering::Greater,
+        (Value::Range(_), Value::Range(_)) => Ordering::Equal,
+        (Value::Range(_), _) => Ordering::Less,
+        (_, Value::Range(_)) => Ordering::Greater,
+        _ => Ordering::Equal,
+    }
+}
+
 /// Reverse list: reverse(jeri) → jeri
 fn reverse(args: &[Value]) -> Result<Value, Error> {
     if args.len() != 1 {
@@ -74,44 +93,70 @@ fn reverse(args: &[Value]) -> Result<Value, Error> {
     
     match &args[0] {
         Value::List(elements) => {
-            let mut reversed = elements.clone();
+            let mut reversed = elements.borrow().clone();
             reversed.reverse();
-            Ok(Value::List(reversed))
+            Ok(Value::list(reversed))
         }
         _ => Err(Error::runtime_error("reverse yana bukata jeri (reverse requires a list)")),
     }
 }
 
+/// Elements of a list argument for map, filter, reduce and sort_by
+///
+/// A list is snapshotted, so the callback may modify it. A range is walked lazily:
+/// nothing is materialized up front and each callback call costs a step, so step
+/// limits stop `map(1..1000000000000, f)` instead of a huge allocation.
+fn list_argument(function: &str, value: &Value) -> Result<Box<dyn Iterator<Item = Value>>, Error> {
+    match value {
+        Value::List(elements) => Ok(Box::new(elements.borrow().clone().into_iter())),
+        Value::Range(range) => Ok(Box::new(range.iter().map(Value::Number))),
+        _ => Err(Error::runtime_error(&format!(
+            "{} na farko yana bukata jeri ({} first argument must be a list)",
+            function, function
+        ))),
+    }
+}
+
 /// Filter list with function: filter(jeri, aiki) → jeri
-fn filter(args: &[Value]) -> Result<Value, Error> {
-    if args.len() != 2 {
-        return Err(Error::runtime_error("filter yana bukata gwargwajen 2 (filter requires exactly 2 arguments)"));
+fn filter(interpreter: &mut Interpreter, args: &[Value]) -> Result<Value, Error> {
+    let elements = list_argument("filter", &args[0])?;
+
+    let mut kept = Vec::new();
+    for element in elements {
+        if interpreter.call_value(&args[1], vec![element.clone()])?.is_truthy() {
+            kept.push(element);
+        }
     }
-    
-    let _list = match &args[0] {
-        Value::List(elements) => elements,
-        _ => return Err(Error::runtime_error("filter na farko yana bukata jeri (filter first argument must be a list)")),
-    };
-    
-    // TODO: Implement function calling for filter
-    // For now, return an error since we can't call user functions yet
-    Err(Error::runtime_error("filter aiki ba a aiwatar ba (filter function not yet implemented)"))
+    Ok(Value::list(kept))
 }
 
 /// Transform list elements: map(jeri, aiki) → jeri
-fn map(args: &[Value]) -> Result<Value, Error> {
-    if args.len() != 2 {
-        return Err(Error::runtime_error("map yana bukata gwargwajen 2 (map requires exactly 2 arguments)"));
+fn map(interpreter: &mut Interpreter, args: &[Value]) -> Result<Value, Error> {
+    let elements = list_argument("map", &args[0])?;
+
+    let mut mapped = Vec::new();
+    for element in elements {
+        mapped.push(interpreter.call_value(&args[1], vec![element])?);
     }
-    
-    let _list = match &args[0] {
-        Value::List(elements) => elements,
-        _ => return Err(Error::runtime_error("map na farko yana bukata jeri (map first argument must be a list)")),
+    Ok(Value::list(mapped))
+}
+
+/// Combine list elements: reduce(jeri, aiki, farko) → abu
+///
+/// Without an initial value the first element is used; an empty list then is an error.
+fn reduce(interpreter: &mut Interpreter, args: &[Value]) -> Result<Value, Error> {
+    let mut elements = list_argument("reduce", &args[0])?;
+    let mut accumulator = match args.get(2) {
+        Some(initial) => initial.clone(),
+        None => elements.next().ok_or_else(|| Error::runtime_error(
+            "reduce ba zai iya aiki da jeri mara komai ba sai da ƙimar farko (reduce of an empty list needs an initial value)"
+        ))?,
     };
-    
-    // TODO: Implement function calling for map
-    // For now, return an error since we can't call user functions yet
-    Err(Error::runtime_error("map aiki ba a aiwatar ba (map function not yet implemented)"))
+
+    for element in elements {
+        accumulator = interpreter.call_value(&args[1], vec![accumulator, element])?;
+    }
+    Ok(accumulator)
 }
 
 /// Sum all numbers in list: sum(jeri) → lamba
@@ -122,24 +167,38 @@ fn sum(args: &[Value]) -> Result<Value, Error> {
     
     match &args[0] {
         Value::List(elements) => {
-            let mut total = 0.0;
+            // Integers are summed exactly; a single float switches to a float total
+            // and a single kuɗi to an exact decimal total (kuɗi and floats never mix)
+            let mut int_total: i64 = 0;
+            let mut float_total = 0.0;
             let mut has_float = false;
+            let mut decimal_total: Option<Decimal> = None;
             
-            for element in elements {
+            for element in elements.borrow().iter() {
                 match element {
-                    Value::Number(n) => total += *n as f64,
+                    Value::Number(n) => {
+                        int_total = int_total.checked_add(*n)
+                            .ok_or_else(|| Error::integer_overflow("sum"))?;
+                    }
                     Value::Float(f) => {
-                        total += f;
+                        float_total += f;
                         has_float = true;
                     }
+                    Value::Decimal(d) => {
+                        let total = decimal_total.unwrap_or(Decimal::from_int(0));
+                        decimal_total = Some(total.checked_add(d).ok_or_else(|| Error::integer_overflow("sum"))?);
+                    }
                     _ => return Err(Error::runtime_error("sum duka abubuwan suna bukata lamba (sum all elements must be numbers)")),
                 }
             }
             
-            if has_float {
-                Ok(Value::Float(total))
-            } else {
-                Ok(Value::Number(total as i64))
+            match decimal_total {
+                Some(_) if has_float => Err(Error::invalid_operation("sum", "kuɗi", "lambar mai daɗewa")),
+                Some(total) => total.checked_add(&Decimal::from_int(int_total))
+                    .map(Value::Decimal)
+                    .ok_or_else(|| Error::integer_overflow("sum")),
+                None if has_float => Ok(Value::Float(float_total + int_total as f64)),
+                None => Ok(Value::Number(int_total)),
             }
         }
         _ => Err(Error::runtime_error("sum yana bukata jeri (sum requires a list)")),
@@ -2,30 +2,37 @@
 //! 
 //! Provides basic arithmetic, trigonometry, and mathematical constants
 
-use crate::interpreter::{Interpreter, Value, Function};
+use std::cmp::Ordering;
+
+use crate::interpreter::{Arity, Interpreter, Value};
+use crate::decimal::{Decimal, RoundingMode};
 use crate::error::Error;
 
 /// Register all mathematical functions with the interpreter
 pub fn register_math_functions(interpreter: &mut Interpreter) -> Result<(), Error> {
     // Basic arithmetic functions
-    register_function(interpreter, "abs", abs)?;
-    register_function(interpreter, "sqrt", sqrt)?;
-    register_function(interpreter, "pow", pow)?;
-    register_function(interpreter, "min", min)?;
-    register_function(interpreter, "max", max)?;
+    interpreter.register_native("abs", Arity::Exact(1), abs);
+    interpreter.register_native("sqrt", Arity::Exact(1), sqrt);
+    interpreter.register_native("pow", Arity::Exact(2), pow);
+    interpreter.register_native("min", Arity::AtLeast(1), min);
+    interpreter.register_native("max", Arity::AtLeast(1), max);
     
     // Rounding functions
-    register_function(interpreter, "floor", floor)?;
-    register_function(interpreter, "ceil", ceil)?;
-    register_function(interpreter, "round", round)?;
+    interpreter.register_native("floor", Arity::Exact(1), floor);
+    interpreter.register_native("ceil", Arity::Exact(1), ceil);
+    interpreter.register_native("round", Arity::Exact(1), round);
+    
+    // Exact decimal (kuɗi) functions
+    interpreter.register_native("raba_kudi", Arity::Range(3, 4), raba_kudi);
+    interpreter.register_native("zagaye_kudi", Arity::Range(2, 3), zagaye_kudi);
     
     // Trigonometric functions
-    register_function(interpreter, "sin", sin)?;
-    register_function(interpreter, "cos", cos)?;
-    register_function(interpreter, "tan", tan)?;
-    register_function(interpreter, "asin", asin)?;
-    register_function(interpreter, "acos", acos)?;
-    register_function(interpreter, "atan", atan)?;
+    interpreter.register_native("sin", Arity::Exact(1), sin);
+    interpreter.register_native("cos", Arity::Exact(1), cos);
+    interpreter.register_native("tan", Arity::Exact(1), tan);
+    interpreter.register_native("asin", Arity::Exact(1), asin);
+    interpreter.register_native("acos", Arity::Exact(1), acos);
+    interpreter.register_native("atan", Arity::Exact(1), atan);
     
     // Mathematical constants
     interpreter.set_variable_value("DABARAN_PI".to_string(), Value::Float(std::f64::consts::PI));
@@ -34,23 +41,6 @@ pub fn register_math_functions(interpreter: &mut Interpreter) -> Result<(), Erro
     Ok(())
 }
 
-/// Register a single function with the interpreter
-fn register_function(interpreter: &mut Interpreter, name: &str, func: fn(&[Value]) -> Result<Value, Error>) -> Result<(), Error> {
-    let function = Function {
-        parameters: vec![], // Will be checked at runtime
-        body: vec![], // Native implementation
-    };
-    
-    // Store the function in a special native functions map
-    // For now, we'll use the regular function registry
-    interpreter.functions.insert(name.to_string(), function);
-    
-    // TODO: Store native function pointers separately
-    // This is a placeholder - we need to extend the interpreter to support native functions
-    
-    Ok(())
-}
-
 // Mathematical function implementations
 
 /// Absolute value: abs(lamba) → lamba
@@ -60,8 +50,12 @@ fn abs(args: &[Value]) -> Result<Value, Error> {
     }
     
     match &args[0] {
-        Value::Number(n) => Ok(Value::Number(n.abs())),
+        Value::Number(n) => n.checked_abs().map(Value::Number).ok_or_else(|| Error::integer_overflow("abs")),
         Value::Float(f) => Ok(Value::Float(f.abs())),
+        Value::Decimal(d) if *d < Decimal::from_int(0) => {
+            d.checked_neg().map(Value::Decimal).ok_or_else(|| Error::integer_overflow("abs"))
+        }
+        Value::Decimal(d) => Ok(Value::Decimal(*d)),
         _ => Err(Error::runtime_error("abs yana bukata lamba (abs requires a number)")),
     }
 }
@@ -110,11 +104,68 @@ fn pow(args: &[Value]) -> Result<Value, Error> {
     Ok(Value::Float(base.powf(exp)))
 }
 
+/// Divide decimals: raba_kudi(a, b, wurare, yanayi?) → kuɗi
+///
+/// `wurare` is the number of decimal places; `yanayi` is the rounding mode
+/// ("rabi" by default, "banki", "ƙasa" or "sama").
+fn raba_kudi(args: &[Value]) -> Result<Value, Error> {
+    let a = decimal_argument(&args[0], "raba_kudi")?;
+    let b = decimal_argument(&args[1], "raba_kudi")?;
+    let (places, mode) = rounding_arguments(&args[2..], "raba_kudi")?;
+    
+    Ok(Value::Decimal(a.checked_div(&b, places, mode)?))
+}
+
+/// Round a decimal: zagaye_kudi(kuɗi, wurare, yanayi?) → kuɗi
+fn zagaye_kudi(args: &[Value]) -> Result<Value, Error> {
+    let value = decimal_argument(&args[0], "zagaye_kudi")?;
+    let (places, mode) = rounding_arguments(&args[1..], "zagaye_kudi")?;
+    
+    Ok(Value::Decimal(value.round(places, mode)?))
+}
+
+/// Decimal argument: a kuɗi or an integer
+fn decimal_argument(value: &Value, name: &str) -> Result<Decimal, Error> {
+    match value {
+        Value::Decimal(d) => Ok(*d),
+        Value::Number(n) => Ok(Decimal::from_int(*n)),
+        _ => Err(Error::runtime_error(&format!(
+            "{} yana bukata kuɗi ko lambar, ba {} ba ({} requires decimals)",
+            name, value.type_name(), name
+        ))),
+    }
+}
+
+/// Number of decimal places and optional rounding mode
+fn rounding_arguments(args: &[Value], name: &str) -> Result<(u32, RoundingMode), Error> {
+    let places = match &args[0] {
+        Value::Number(n) if u32::try_from(*n).is_ok() => *n as u32,
+        _ => return Err(Error::runtime_error(&format!(
+            "{} yana bukata wurare a matsayin lambar da ba ta ƙasa da sifili (decimal places must be a non-negative integer)",
+            name
+        ))),
+    };
+    
+    let mode = match args.get(1) {
+        None => RoundingMode::HalfUp,
+        Some(Value::String(mode)) => RoundingMode::from_name(mode)?,
+        Some(_) => return Err(Error::runtime_error(&format!(
+            "{} yana bukata yanayin zagaye a matsayin jimla (rounding mode must be a string)",
+            name
+        ))),
+    };
+    
+    Ok((places, mode))
+}
+
 /// Minimum: min(a, b, ...) → lamba
 fn min(args: &[Value]) -> Result<Value, Error> {
     if args.is_empty() {
         return Err(Error::runtime_error("min yana bukata aƙalla daya (min requires at least one argument)"));
     }
+    if args.iter().any(|arg| matches!(arg, Value::Decimal(_))) {
+        return decimal_extreme(args, "min", Ordering::Less);
+    }
     
     let mut min_val = match &args[0] {
         Value::Number(n) => *n as f64,
@@ -147,6 +198,9 @@ fn max(args: &[Value]) -> Result<Value, Error> {
     if args.is_empty() {
         return Err(Error::runtime_error("max yana bukata aƙalla daya (max requires at least one argument)"));
     }
+    if args.iter().any(|arg| matches!(arg, Value::Decimal(_))) {
+        return decimal_extreme(args, "max", Ordering::Greater);
+    }
     
     let mut max_val = match &args[0] {
         Value::Number(n) => *n as f64,
@@ -174,6 +228,34 @@ fn max(args: &[Value]) -> Result<Value, Error> {
     }
 }
 
+/// Smallest (`Ordering::Less`) or largest argument once a kuɗi is involved
+///
+/// Integers compare exactly against kuɗi and keep their type; floats are
+/// rejected like in arithmetic, so no decimal is ever rounded through f64.
+fn decimal_extreme(args: &[Value], name: &str, pick: Ordering) -> Result<Value, Error> {
+    let mut values = Vec::with_capacity(args.len());
+    for arg in args {
+        values.push(match arg {
+            Value::Decimal(d) => *d,
+            Value::Number(n) => Decimal::from_int(*n),
+            Value::Float(_) => return Err(Error::invalid_operation(name, "kuɗi", "lambar mai daɗewa")),
+            _ => return Err(Error::runtime_error(&format!(
+                "{} duka sunayen suna bukata lamba ({} all arguments must be numbers)",
+                name, name
+            ))),
+        });
+    }
+    
+    let mut best = 0;
+    for (i, val) in values.iter().enumerate().skip(1) {
+        if val.cmp(&values[best]) == pick {
+            best = i;
+        }
+    }
+    
+    Ok(args[best].clone())
+}
+
 /// Floor: floor(lamba) → lamba
 fn floor(args: &[Value]) -> Result<Value, Error> {
     if args.len() != 1 {
@@ -209,6 +291,10 @@ fn round(args: &[Value]) -> Result<Value, Error> {
     match &args[0] {
         Value::Number(n) => Ok(Value::Number(*n)),
         Value::Float(f) => Ok(Value::Number(f.round() as i64)),
+        Value::Decimal(d) => d.round(0, RoundingMode::HalfUp)?
+            .trunc_to_i64()
+            .map(Value::Number)
+            .ok_or_else(|| Error::integer_overflow("round")),
         _ => Err(Error::runtime_error("round yana bukata lamba (round requires a number)")),
     }
 }
@@ -270,7 +356,7 @@ fn asin(args: &[Value]) -> Result<Value, Error> {
         _ => return Err(Error::runtime_error("asin yana bukata lamba (asin requires a number)")),
     };
     
-    if value < -1.0 || value > 1.0 {
+    if !(-1.0..=1.0).contains(&value) {
         return Err(Error::runtime_error("asin yana bukata tsakanin -1 da 1 (asin requires value between -1 and 1)"));
     }
     
@@ -289,7 +375,7 @@ fn acos(args: &[Value]) -> Result<Value, Error> {
         _ => return Err(Error::runtime_error("acos yana bukata lamba (acos requires a number)")),
     };
     
-    if value < -1.0 || value > 1.0 {
+    if !(-1.0..=1.0).contains(&value) {
         return Err(Error::runtime_error("acos yana bukata tsakanin -1 da 1 (acos requires value between -1 and 1)"));
     }
     
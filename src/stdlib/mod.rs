@@ -7,6 +7,7 @@ pub mod math;
 pub mod convert;
 pub mod string_utils;
 pub mod list_utils;
+pub mod input;
 
 use crate::interpreter::Interpreter;
 use crate::error::Error;
@@ -25,5 +26,8 @@ pub fn register_stdlib(interpreter: &mut Interpreter) -> Result<(), Error> {
     // Register list utilities
     list_utils::register_list_functions(interpreter)?;
     
+    // Register typed input functions
+    input::register_input_functions(interpreter)?;
+    
     Ok(())
 }
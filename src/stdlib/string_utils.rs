@@ -2,31 +2,16 @@
 //! 
 //! Provides functions for string manipulation and analysis
 
-use crate::interpreter::{Interpreter, Value, Function};
+use crate::interpreter::{Arity, Interpreter, Value};
 use crate::error::Error;
 
 /// Register all string utility functions with the interpreter
 pub fn register_string_functions(interpreter: &mut Interpreter) -> Result<(), Error> {
-    register_function(interpreter, "contains", contains)?;
-    register_function(interpreter, "starts_with", starts_with)?;
-    register_function(interpreter, "ends_with", ends_with)?;
-    register_function(interpreter, "replace", replace)?;
-    register_function(interpreter, "trim", trim)?;
-    
-    Ok(())
-}
-
-/// Register a single function with the interpreter
-fn register_function(interpreter: &mut Interpreter, name: &str, func: fn(&[Value]) -> Result<Value, Error>) -> Result<(), Error> {
-    let function = Function {
-        parameters: vec![], // Will be checked at runtime
-        body: vec![], // Native implementation
-    };
-    
-    interpreter.functions.insert(name.to_string(), function);
-    
-    // TODO: Store native function pointers separately
-    // This is a placeholder - we need to extend the interpreter to support native functions
+    interpreter.register_native("contains", Arity::Exact(2), contains);
+    interpreter.register_native("starts_with", Arity::Exact(2), starts_with);
+    interpreter.register_native("ends_with", Arity::Exact(2), ends_with);
+    interpreter.register_native("replace", Arity::Exact(3), replace);
+    interpreter.register_native("trim", Arity::Exact(1), trim);
     
     Ok(())
 }
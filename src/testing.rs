@@ -0,0 +1,96 @@
+//! Exécution des blocs de test `gwaji` (`dabara test`)
+//!
+//! Un fichier de test est un programme ordinaire : son code de premier niveau
+//! (fonctions, variables) prépare les tests, et chaque bloc `gwaji "suna" { ... }`
+//! s'exécute ensuite dans un interpréteur neuf, pour qu'aucun test ne dépende d'un autre.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::error::Error;
+use crate::host::BufferHost;
+use crate::interpreter::Interpreter;
+use crate::lexer::Span;
+use crate::parser::{Program, Statement, StatementKind};
+
+/// Fin du nom des fichiers que `dabara test` exécute
+pub const TEST_FILE_SUFFIX: &str = "_test.ha";
+
+/// Résultat d'un bloc gwaji
+#[derive(Debug, Clone)]
+pub struct TestOutcome {
+    /// Nom donné au bloc
+    pub name: String,
+    /// Position du mot-clé `gwaji`
+    pub span: Span,
+    /// Tout ce que le test a écrit, préparation comprise
+    pub output: String,
+    /// Erreur qui a fait échouer le test
+    pub error: Option<Error>,
+}
+
+impl TestOutcome {
+    /// Vérifie si le test a réussi
+    pub fn passed(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Exécute chaque bloc gwaji d'un programme, dans l'ordre du fichier
+///
+/// `configure` règle chaque interpréteur neuf (limites d'exécution, ...) avant le test.
+/// La sortie est capturée; `karɓa` y voit la fin de l'entrée.
+pub fn run_tests(program: &Program, configure: impl Fn(&mut Interpreter)) -> Vec<TestOutcome> {
+    let setup: Vec<&Statement> = program.statements.iter()
+        .filter(|statement| !matches!(statement.kind, StatementKind::Test { .. }))
+        .collect();
+
+    program.statements.iter()
+        .filter_map(|statement| match &statement.kind {
+            StatementKind::Test { name, body } => Some((name, body, statement.span)),
+            _ => None,
+        })
+        .map(|(name, body, span)| {
+            let host = BufferHost::new();
+            let mut interpreter = Interpreter::new();
+            interpreter.set_host(Box::new(host.clone()));
+            configure(&mut interpreter);
+
+            let statements = setup.iter().copied().chain(body).cloned().collect();
+            let error = interpreter.execute(Program { statements }).err();
+
+            TestOutcome { name: name.clone(), span, output: host.output(), error }
+        })
+        .collect()
+}
+
+/// Fichiers `*_test.ha` d'un dossier et de ses sous-dossiers, triés par chemin
+///
+/// Les dossiers cachés (`.git`, ...) sont ignorés. Un chemin de fichier est retourné tel quel.
+pub fn find_test_files(path: &Path) -> io::Result<Vec<PathBuf>> {
+    if path.is_file() {
+        return Ok(vec![path.to_path_buf()]);
+    }
+
+    let mut files = Vec::new();
+    let mut pending = vec![path.to_path_buf()];
+
+    while let Some(dir) = pending.pop() {
+        for entry in fs::read_dir(&dir)? {
+            let entry_path = entry?.path();
+            let name = entry_path.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+
+            if entry_path.is_dir() {
+                if !name.starts_with('.') {
+                    pending.push(entry_path);
+                }
+            } else if name.ends_with(TEST_FILE_SUFFIX) {
+                files.push(entry_path);
+            }
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
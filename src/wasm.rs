@@ -2,12 +2,71 @@
 //!
 //! This module provides WebAssembly bindings to run Dabara code in browsers.
 
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use js_sys::Function;
 use wasm_bindgen::prelude::*;
 use crate::{tokenize, parse, Interpreter};
+use crate::error::{Error, RenderStyle};
+use crate::host::{BufferHost, Host, ScriptedHost};
+use crate::interpreter::BROWSER_MAX_CALL_DEPTH;
+
+/// Nom affiché dans les diagnostics du playground
+const PLAYGROUND_FILE: &str = "<playground>";
 
 /// Result type for WASM operations
 pub type WasmResult = Result<String, JsValue>;
 
+/// JavaScript handlers registered on a runtime
+#[derive(Default)]
+struct JsHandlers {
+    /// Called with each piece of output as soon as it is written
+    on_output: Option<Function>,
+    /// Called with the pending prompt; returns the line read, or `null` at end of input
+    on_input: Option<Function>,
+    /// Last prompt written, handed to `on_input`
+    pending_prompt: String,
+}
+
+/// Browser I/O for the interpreter
+///
+/// Output is always captured so that `run_code` can return it, and is also streamed
+/// to the output handler when one is set. Input comes from the input handler; without
+/// one, `karɓa` sees the end of input.
+#[derive(Clone, Default)]
+struct JsHost {
+    buffer: BufferHost,
+    handlers: Rc<RefCell<JsHandlers>>,
+}
+
+impl Host for JsHost {
+    fn write(&mut self, text: &str) {
+        self.buffer.write(text);
+        if let Some(on_output) = &self.handlers.borrow().on_output {
+            let _ = on_output.call1(&JsValue::NULL, &JsValue::from_str(text));
+        }
+    }
+
+    fn prompt(&mut self, text: &str) {
+        self.write(text);
+        self.handlers.borrow_mut().pending_prompt = text.to_string();
+    }
+
+    fn read_line(&mut self) -> Result<Option<String>, Error> {
+        let handlers = self.handlers.borrow();
+        let Some(on_input) = &handlers.on_input else {
+            return Ok(None);
+        };
+
+        let line = on_input
+            .call1(&JsValue::NULL, &JsValue::from_str(&handlers.pending_prompt))
+            .map_err(|_| Error::runtime_error("Ba za a iya karba shigarwa ba (Impossible de lire l'entrée)"))?;
+        Ok(line.as_string())
+    }
+}
+
 /// WASM-compatible runtime for Dabara
 ///
 /// This struct wraps the interpreter and provides JavaScript-accessible methods
@@ -15,7 +74,11 @@ pub type WasmResult = Result<String, JsValue>;
 #[wasm_bindgen]
 pub struct DabaraRuntime {
     interpreter: Interpreter,
-    output_buffer: String,
+    host: JsHost,
+    /// Lines replayed to `karɓa` on every run, instead of the input handler
+    script: Option<Vec<String>>,
+    /// Whether replayed answers are written after their prompts
+    transcript: bool,
 }
 
 #[wasm_bindgen]
@@ -32,10 +95,12 @@ impl DabaraRuntime {
         #[cfg(feature = "wasm")]
         console_error_panic_hook::set_once();
 
-        DabaraRuntime {
-            interpreter: Interpreter::new(),
-            output_buffer: String::new(),
-        }
+        let host = JsHost::default();
+        let mut interpreter = Interpreter::new();
+        interpreter.set_host(Box::new(host.clone()));
+        interpreter.set_max_call_depth(BROWSER_MAX_CALL_DEPTH);
+
+        DabaraRuntime { interpreter, host, script: None, transcript: false }
     }
 
     /// Runs Dabara source code and returns the output
@@ -45,7 +110,7 @@ impl DabaraRuntime {
     ///
     /// # Returns
     /// * `Ok(String)` - The captured output from the program
-    /// * `Err(JsValue)` - Error message if execution fails
+    /// * `Err(JsValue)` - Plain-text diagnostic (source line, caret and call traceback) if execution fails
     ///
     /// # Example (JavaScript)
     /// ```javascript
@@ -59,24 +124,30 @@ impl DabaraRuntime {
     /// ```
     #[wasm_bindgen]
     pub fn run_code(&mut self, source: &str) -> WasmResult {
-        self.output_buffer.clear();
+        self.host.buffer.take_output();
 
-        // Tokenize
-        let tokens = match tokenize(source) {
-            Ok(t) => t,
-            Err(e) => return Err(JsValue::from_str(&e.to_string())),
+        // Each run replays the prepared input from its first line
+        match &self.script {
+            Some(lines) => self.interpreter.set_host(Box::new(
+                ScriptedHost::new(self.host.clone(), lines.iter().cloned()).with_transcript(self.transcript),
+            )),
+            None => self.interpreter.set_host(Box::new(self.host.clone())),
+        }
+
+        let diagnostic = |e: Error| {
+            JsValue::from_str(&e.render(source, PLAYGROUND_FILE, RenderStyle::Plain))
         };
 
+        // Tokenize
+        let tokens = tokenize(source).map_err(diagnostic)?;
+
         // Parse
-        let program = match parse(tokens) {
-            Ok(p) => p,
-            Err(e) => return Err(JsValue::from_str(&e.to_string())),
-        };
+        let program = parse(tokens).map_err(diagnostic)?;
 
-        // Execute with output capture
-        match self.interpreter.execute_with_output(program, &mut self.output_buffer) {
-            Ok(_) => Ok(self.output_buffer.clone()),
-            Err(e) => Err(JsValue::from_str(&e.to_string())),
+        // Execute; the host captures the output
+        match self.interpreter.execute(program) {
+            Ok(_) => Ok(self.host.buffer.take_output()),
+            Err(e) => Err(diagnostic(e)),
         }
     }
 
@@ -127,7 +198,80 @@ impl DabaraRuntime {
     #[wasm_bindgen]
     pub fn reset(&mut self) {
         self.interpreter.clear_variables();
-        self.output_buffer.clear();
+        self.host.buffer.take_output();
+    }
+
+    /// Sets the maximum number of nested function calls
+    ///
+    /// Runaway recursion stops with a Hausa "recursion too deep" error once this
+    /// depth is reached, instead of exhausting the WebAssembly stack. The default is
+    /// `BROWSER_MAX_CALL_DEPTH` (100): each call takes several KB of a 1 MB stack, so
+    /// much higher values crash the page instead of raising the error.
+    #[wasm_bindgen]
+    pub fn set_max_call_depth(&mut self, depth: usize) {
+        self.interpreter.set_max_call_depth(depth);
+    }
+
+    /// Limits how many steps (loop iterations and function calls) each run may take
+    ///
+    /// Pass `undefined` to remove the limit. A run that exhausts the budget stops
+    /// with a "Kuskure na Iyaka" diagnostic that `kama` cannot catch.
+    #[wasm_bindgen]
+    pub fn set_step_limit(&mut self, steps: Option<u32>) {
+        self.interpreter.set_step_limit(steps.map(u64::from));
+    }
+
+    /// Limits how long each run may take, in milliseconds
+    ///
+    /// Pass `undefined` to remove the limit. The clock is checked in loops and calls.
+    #[wasm_bindgen]
+    pub fn set_timeout_ms(&mut self, milliseconds: Option<u32>) {
+        self.interpreter.set_time_limit(milliseconds.map(|ms| Duration::from_millis(u64::from(ms))));
+    }
+
+    /// Streams output to `handler(text)` as the program writes it
+    ///
+    /// `run_code` still returns the whole output. Pass `undefined` to stop streaming.
+    #[wasm_bindgen]
+    pub fn set_output_handler(&mut self, handler: Option<Function>) {
+        self.host.handlers.borrow_mut().on_output = handler;
+    }
+
+    /// Answers `karɓa` with `handler(prompt)`
+    ///
+    /// The handler returns the line typed by the user, or `null` at the end of input.
+    ///
+    /// # Example (JavaScript)
+    /// ```javascript
+    /// runtime.set_input_handler((prompt) => window.prompt(prompt));
+    /// ```
+    #[wasm_bindgen]
+    pub fn set_input_handler(&mut self, handler: Option<Function>) {
+        self.host.handlers.borrow_mut().on_input = handler;
+    }
+
+    /// Answers `karɓa` from a prepared list of lines, one per read
+    ///
+    /// Every `run_code` replays the list from the start, so runs are reproducible.
+    /// Reading past the last line fails with a "scripted input exhausted" error.
+    /// Pass `undefined` to go back to the input handler.
+    ///
+    /// # Example (JavaScript)
+    /// ```javascript
+    /// runtime.set_scripted_input(["Musa", "25"]);
+    /// runtime.set_input_transcript(true);
+    /// runtime.run_code('fara\n  rubuta "Sannu " + karɓa("Suna? ")\nkare');
+    /// // "Suna? Musa\nSannu Musa\n"
+    /// ```
+    #[wasm_bindgen]
+    pub fn set_scripted_input(&mut self, lines: Option<Vec<String>>) {
+        self.script = lines;
+    }
+
+    /// Writes each scripted answer after its prompt, as if it had been typed
+    #[wasm_bindgen]
+    pub fn set_input_transcript(&mut self, transcript: bool) {
+        self.transcript = transcript;
     }
 
     /// Gets the value of a variable
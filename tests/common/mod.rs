@@ -0,0 +1,89 @@
+//! Fonctions partagées par les tests d'intégration
+//!
+//! Chaque fichier de test n'en utilise qu'une partie.
+#![allow(dead_code)]
+
+use dabara::host::BufferHost;
+use dabara::{lexer::tokenize, parser::parse, interpreter::{Interpreter, Value}, Error};
+
+/// Exécute un programme et retourne l'interpréteur
+pub fn run(source: &str) -> Result<Interpreter, Error> {
+    let tokens = tokenize(source)?;
+    let program = parse(tokens)?;
+    let mut interpreter = Interpreter::new();
+    interpreter.execute(program)?;
+    Ok(interpreter)
+}
+
+/// Exécute un programme et retourne l'erreur produite
+pub fn run_error(source: &str) -> Error {
+    let result = tokenize(source)
+        .and_then(parse)
+        .and_then(|program| Interpreter::new().execute(program));
+
+    result.expect_err("Expected an error")
+}
+
+/// Exécute un programme avec un tampon en mémoire et retourne sa sortie
+pub fn run_with_output(source: &str) -> Result<String, Error> {
+    run_with_input(source, &[])
+}
+
+/// Exécute un programme avec ces lignes en entrée et retourne sa sortie
+pub fn run_with_input(source: &str, input: &[&str]) -> Result<String, Error> {
+    let host = BufferHost::with_input(input.iter().copied());
+    let mut interpreter = Interpreter::new();
+    interpreter.set_host(Box::new(host.clone()));
+    interpreter.execute(parse(tokenize(source)?)?)?;
+    Ok(host.output())
+}
+
+/// Exécute sur un fil à grande pile, comme le fait `dabara` en ligne de commande
+///
+/// En mode debug, chaque niveau d'imbrication coûte plusieurs dizaines de Ko de pile.
+pub fn with_large_stack<T: Send + 'static>(run: impl FnOnce() -> T + Send + 'static) -> T {
+    std::thread::Builder::new()
+        .stack_size(256 * 1024 * 1024)
+        .spawn(run)
+        .unwrap()
+        .join()
+        .unwrap()
+}
+
+/// Exécute sur un fil à petite pile, comme la pile WebAssembly du navigateur
+///
+/// 1 Mo en release comme dans le navigateur; un appel coûte plutôt 50 Ko en debug,
+/// on y prend donc 8 Mo, la pile du fil principal sous Linux.
+pub fn with_small_stack<T: Send + 'static>(run: impl FnOnce() -> T + Send + 'static) -> T {
+    let size = if cfg!(debug_assertions) { 8 * 1024 * 1024 } else { 1024 * 1024 };
+    std::thread::Builder::new()
+        .stack_size(size)
+        .spawn(run)
+        .unwrap()
+        .join()
+        .unwrap()
+}
+
+/// Retourne l'affichage d'une variable globale
+pub fn shown(interpreter: &Interpreter, name: &str) -> String {
+    match interpreter.get_variable(name) {
+        Some(value) => value.to_string(),
+        None => panic!("Variable '{}' not found", name),
+    }
+}
+
+/// Retourne la valeur entière d'une variable globale
+pub fn number(interpreter: &Interpreter, name: &str) -> i64 {
+    match interpreter.get_variable(name) {
+        Some(Value::Number(n)) => *n,
+        other => panic!("Expected number for '{}', got {:?}", name, other),
+    }
+}
+
+/// Retourne la valeur booléenne d'une variable globale
+pub fn boolean(interpreter: &Interpreter, name: &str) -> bool {
+    match interpreter.get_variable(name) {
+        Some(Value::Boolean(b)) => *b,
+        other => panic!("Expected boolean for '{}', got {:?}", name, other),
+    }
+}
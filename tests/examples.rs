@@ -0,0 +1,182 @@
+//! Sorties attendues des exemples (golden files)
+//!
+//! Chaque `examples/*.ha` passe par tokenize → parse → Interpreter, sortie capturée,
+//! et le résultat est comparé au fichier `.expected` voisin. Un fichier `.input` voisin
+//! répond aux `karɓa`, ligne par ligne, et chaque réponse est écrite après son invite.
+//!
+//! Un exemple qui échoue est une régression, sauf s'il porte la ligne
+//! `# ana tsammanin kuskure` (« une erreur est attendue ») : son diagnostic est alors
+//! écrit après sa sortie, et l'exemple échoue s'il cesse de produire une erreur.
+//!
+//! ```text
+//! cargo test --test examples                   # vérifier tous les exemples
+//! cargo test --test examples -- fonctions      # seulement les noms qui contiennent « fonctions »
+//! cargo test --test examples -- --bless        # régénérer les fichiers .expected
+//! ```
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use dabara::error::RenderStyle;
+use dabara::host::{BufferHost, ScriptedHost};
+use dabara::{parse, tokenize, Interpreter};
+
+/// Dossier des exemples, relatif à la racine du dépôt
+const EXAMPLES_DIR: &str = "examples";
+
+/// Budget de pas : un exemple qui boucle sans fin échoue au lieu de bloquer les tests
+const EXAMPLE_STEP_LIMIT: u64 = 10_000_000;
+
+/// Pile du fil qui exécute un exemple, comme pour `dabara` en ligne de commande
+const EXAMPLE_STACK_SIZE: usize = 256 * 1024 * 1024;
+
+/// Ligne de commentaire qui déclare qu'un exemple doit se terminer par une erreur
+const EXPECTED_FAILURE_MARKER: &str = "# ana tsammanin kuskure";
+
+fn main() -> ExitCode {
+    let mut bless = false;
+    let mut filters = Vec::new();
+    for argument in std::env::args().skip(1) {
+        match argument.as_str() {
+            "--bless" => bless = true,
+            // Options de libtest (--quiet, --nocapture, ...) sans objet ici
+            other if other.starts_with('-') => {}
+            other => filters.push(other.to_string()),
+        }
+    }
+
+    let examples: Vec<PathBuf> = list_examples(Path::new(EXAMPLES_DIR))
+        .into_iter()
+        .filter(|path| filters.is_empty() || filters.iter().any(|f| path.to_string_lossy().contains(f.as_str())))
+        .collect();
+
+    println!("\nrunning {} examples", examples.len());
+
+    let mut failures = Vec::new();
+    for path in &examples {
+        let run = run_example(path);
+        let actual = run.output;
+        let expected_path = path.with_extension("expected");
+
+        if run.failed != run.expects_failure {
+            let problem = if run.failed {
+                format!("unexpected error (add `{}` if the error is intended)", EXPECTED_FAILURE_MARKER)
+            } else {
+                format!("marked `{}` but finished without an error", EXPECTED_FAILURE_MARKER)
+            };
+            println!("example {} ... FAILED", path.display());
+            failures.push(format!("{}: {}\n{}", path.display(), problem, actual));
+            continue;
+        }
+
+        if bless {
+            fs::write(&expected_path, &actual).expect("cannot write .expected file");
+            println!("example {} ... blessed", path.display());
+            continue;
+        }
+
+        match fs::read_to_string(&expected_path) {
+            Ok(expected) if expected == actual => println!("example {} ... ok", path.display()),
+            Ok(expected) => {
+                println!("example {} ... FAILED", path.display());
+                failures.push(format!("{}: output differs from {}\n{}", path.display(), expected_path.display(), diff(&expected, &actual)));
+            }
+            Err(_) => {
+                println!("example {} ... FAILED", path.display());
+                failures.push(format!("{}: missing {} (run with --bless to create it)", path.display(), expected_path.display()));
+            }
+        }
+    }
+
+    for failure in &failures {
+        println!("\n---- {}", failure);
+    }
+
+    let passed = examples.len() - failures.len();
+    let status = if failures.is_empty() { "ok" } else { "FAILED" };
+    println!("\nexample result: {}. {} passed; {} failed\n", status, passed, failures.len());
+
+    if failures.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Fichiers `.ha` du dossier des exemples (sans les sous-dossiers), triés
+fn list_examples(dir: &Path) -> Vec<PathBuf> {
+    let mut examples: Vec<PathBuf> = fs::read_dir(dir)
+        .expect("cannot read examples directory")
+        .map(|entry| entry.expect("cannot read examples directory").path())
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "ha"))
+        .collect();
+    examples.sort();
+    examples
+}
+
+/// Résultat d'un exemple
+struct ExampleRun {
+    /// Sortie du programme, suivie du diagnostic s'il échoue
+    output: String,
+    /// Le programme s'est terminé par une erreur
+    failed: bool,
+    /// Le programme porte EXPECTED_FAILURE_MARKER
+    expects_failure: bool,
+}
+
+/// Exécute un exemple et retourne sa sortie, suivie du diagnostic s'il échoue
+fn run_example(path: &Path) -> ExampleRun {
+    let path = path.to_path_buf();
+    std::thread::Builder::new()
+        .stack_size(EXAMPLE_STACK_SIZE)
+        .spawn(move || {
+            let filename = path.to_string_lossy().replace('\\', "/");
+            let source = fs::read_to_string(&path).expect("cannot read example");
+            let input = fs::read_to_string(path.with_extension("input")).unwrap_or_default();
+
+            let output = BufferHost::new();
+            let mut interpreter = Interpreter::new();
+            interpreter.set_host(Box::new(ScriptedHost::from_text(output.clone(), &input).with_transcript(true)));
+            interpreter.set_step_limit(Some(EXAMPLE_STEP_LIMIT));
+
+            let result = tokenize(&source)
+                .and_then(parse)
+                .and_then(|program| interpreter.execute(program));
+
+            let mut actual = output.output();
+            let failed = result.is_err();
+            if let Err(error) = result {
+                actual.push_str(&error.render(&source, &filename, RenderStyle::Plain));
+                actual.push('\n');
+            }
+            ExampleRun {
+                output: actual,
+                failed,
+                expects_failure: source.lines().any(|line| line.trim() == EXPECTED_FAILURE_MARKER),
+            }
+        })
+        .expect("cannot spawn example thread")
+        .join()
+        .expect("example panicked")
+}
+
+/// Lignes qui diffèrent entre la sortie attendue (-) et la sortie obtenue (+)
+fn diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+
+    let mut report = Vec::new();
+    for line in 0..expected.len().max(actual.len()) {
+        let (old, new) = (expected.get(line), actual.get(line));
+        if old != new {
+            if let Some(old) = old {
+                report.push(format!("{:>4} - {}", line + 1, old));
+            }
+            if let Some(new) = new {
+                report.push(format!("{:>4} + {}", line + 1, new));
+            }
+        }
+    }
+    report.join("\n")
+}
@@ -209,7 +209,7 @@ kare
     fn test_tokenizer_identifiers() {
         let tokens = tokenize("sunan lambar shekarun").unwrap();
         
-        match &tokens[0] {
+        match &tokens[0].token {
             Token::Identifier(name) => assert_eq!(name, "sunan"),
             _ => panic!("Expected identifier"),
         }
@@ -219,7 +219,7 @@ kare
     fn test_tokenizer_numbers() {
         let tokens = tokenize("42 0 999").unwrap();
         
-        match &tokens[0] {
+        match &tokens[0].token {
             Token::Number(n) => assert_eq!(*n, 42),
             _ => panic!("Expected number"),
         }
@@ -229,7 +229,7 @@ kare
     fn test_tokenizer_strings() {
         let tokens = tokenize(r#""Sannu" "Ina kwana?""#).unwrap();
         
-        match &tokens[0] {
+        match &tokens[0].token {
             Token::String(s) => assert_eq!(s, "Sannu"),
             _ => panic!("Expected string"),
         }
@@ -697,6 +697,7 @@ fara
 
     // Tests d'exécution pour le mot-clé 'var'
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_execution_var_keyword_numbers() {
         let source = r#"
 fara
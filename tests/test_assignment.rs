@@ -0,0 +1,138 @@
+//! Tests pour l'affectation simple et composée
+
+mod common;
+
+use dabara::{lexer::tokenize, Error, Token};
+use common::{run, number};
+
+#[test]
+fn test_compound_assignment_tokens() {
+    let tokens: Vec<Token> = tokenize("x += 1 -= *= /= =")
+        .unwrap()
+        .into_iter()
+        .map(|t| t.token)
+        .collect();
+
+    assert_eq!(tokens[1], Token::PlusEquals);
+    assert_eq!(tokens[3], Token::MinusEquals);
+    assert_eq!(tokens[4], Token::MultiplyEquals);
+    assert_eq!(tokens[5], Token::DivideEquals);
+    assert_eq!(tokens[6], Token::Equals);
+}
+
+#[test]
+fn test_plain_assignment() {
+    let source = r#"
+fara
+  var x = 1
+  var y = x
+  x = x + 1
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    assert_eq!(number(&interpreter, "x"), 2);
+    assert_eq!(number(&interpreter, "y"), 1);
+}
+
+#[test]
+fn test_compound_assignment() {
+    let source = r#"
+fara
+  var x = 10
+  x += 5
+  x -= 3
+  x *= 4
+  x /= 6
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    assert_eq!(number(&interpreter, "x"), 8);
+}
+
+#[test]
+fn test_assignment_updates_outer_variable() {
+    let source = r#"
+fara
+  var jimla = 0
+  aiki kara(n) {
+    jimla += n
+  }
+  kara(5)
+  kara(7)
+  var i = 0
+  maimaita (i < 3) {
+    i = i + 1
+  }
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    assert_eq!(number(&interpreter, "jimla"), 12);
+    assert_eq!(number(&interpreter, "i"), 3);
+}
+
+#[test]
+fn test_assignment_after_identifier_on_previous_line() {
+    // `var y = x` suivi de `x = 5` ne doit pas devenir l'appel `x(x)`
+    let source = r#"
+fara
+  var x = 1
+  var y = x
+  x = 5
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    assert_eq!(number(&interpreter, "x"), 5);
+    assert_eq!(number(&interpreter, "y"), 1);
+}
+
+#[test]
+fn test_assignment_to_undeclared_variable() {
+    let source = "fara\n  babu = 3\nƙare";
+
+    let error = run(source).err().expect("Expected an error");
+
+    assert!(matches!(error, Error::RuntimeError(..)));
+    assert!(error.message().contains("babu"));
+    assert_eq!(error.span().map(|s| (s.line, s.column)), Some((2, 3)));
+}
+
+#[test]
+fn test_compound_index_assignment_evaluates_target_once() {
+    let source = r#"
+fara
+  var kira = 0
+  aiki f() {
+    kira += 1
+    mayar 0
+  }
+  var jeri = [10, 20]
+  jeri[f()] += 1
+  var m = {"a": 1}
+  aiki maɓalli() {
+    kira += 1
+    mayar "a"
+  }
+  m[maɓalli()] *= 5
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    assert_eq!(number(&interpreter, "kira"), 2);
+    assert_eq!(interpreter.get_variable("jeri").map(|v| v.to_string()).as_deref(), Some("[11, 20]"));
+    assert_eq!(interpreter.get_variable("m").map(|v| v.to_string()).as_deref(), Some("{a: 5}"));
+}
+
+#[test]
+fn test_compound_assignment_error_points_at_operator() {
+    let error = run("fara\nvar x = gaskiya\nx -= 1\nƙare").err().expect("Expected an error");
+    assert_eq!(error.span().map(|s| (s.line, s.column)), Some((3, 3)));
+}
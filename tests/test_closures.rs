@@ -0,0 +1,171 @@
+//! Tests pour les fonctions comme valeurs, les fonctions anonymes et les fermetures
+
+mod common;
+
+use dabara::{lexer::tokenize, parser::parse, interpreter::{Interpreter, Value}};
+use common::{run, shown};
+
+#[test]
+fn test_lambda_stored_in_variable() {
+    let source = r#"
+fara
+  var ninka = aiki(x) { mayar x * 2 }
+  var a = ninka(21)
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(shown(&interpreter, "a"), "42");
+    assert!(matches!(interpreter.get_variable("ninka"), Some(Value::Function(_))));
+}
+
+#[test]
+fn test_closure_keeps_its_environment() {
+    let source = r#"
+fara
+  aiki mai_kirgawa() {
+    var kirga = 0
+    mayar aiki() {
+      kirga += 1
+      mayar kirga
+    }
+  }
+  var k1 = mai_kirgawa()
+  var k2 = mai_kirgawa()
+  k1()
+  k1()
+  var a = k1()
+  var b = k2()
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(shown(&interpreter, "a"), "3");
+    assert_eq!(shown(&interpreter, "b"), "1");
+}
+
+#[test]
+fn test_function_returning_function() {
+    let source = r#"
+fara
+  aiki kara(a) {
+    mayar aiki(b) { mayar a + b }
+  }
+  var kara_goma = kara(10)
+  var a = kara_goma(5)
+  var b = kara(1)(2)
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(shown(&interpreter, "a"), "15");
+    assert_eq!(shown(&interpreter, "b"), "3");
+}
+
+#[test]
+fn test_recursive_lambda() {
+    let source = r#"
+fara
+  var f = aiki(n) {
+    idan n <= 1 {
+      mayar 1
+    }
+    mayar n * f(n - 1)
+  }
+  var a = f(10)
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(shown(&interpreter, "a"), "3628800");
+}
+
+#[test]
+fn test_named_and_native_functions_as_values() {
+    let source = r#"
+fara
+  aiki ninka(x) {
+    mayar x * 2
+  }
+  var a = map([1, 2, 3], ninka)
+  var b = map([-1, 2, -3], abs)
+  var ayyuka = [ninka, aiki(x) { mayar x + 100 }]
+  var c = ayyuka[1](1)
+  var d = string(ninka)
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(shown(&interpreter, "a"), "[2, 4, 6]");
+    assert_eq!(shown(&interpreter, "b"), "[1, 2, 3]");
+    assert_eq!(shown(&interpreter, "c"), "101");
+    assert_eq!(shown(&interpreter, "d"), "<aiki ninka>");
+}
+
+#[test]
+fn test_map_filter_reduce() {
+    let source = r#"
+fara
+  var murabba = map([1, 2, 3], aiki(x) { mayar x * x })
+  var nau_biyu = filter(1..=10, aiki(n) { mayar n % 2 == 0 })
+  var jimilla = reduce([1, 2, 3, 4], aiki(a, b) { mayar a + b })
+  var da_farko = reduce([], aiki(a, b) { mayar a + b }, 10)
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(shown(&interpreter, "murabba"), "[1, 4, 9]");
+    assert_eq!(shown(&interpreter, "nau_biyu"), "[2, 4, 6, 8, 10]");
+    assert_eq!(shown(&interpreter, "jimilla"), "10");
+    assert_eq!(shown(&interpreter, "da_farko"), "10");
+}
+
+#[test]
+fn test_sort_by_key_is_stable() {
+    let source = r#"
+fara
+  var mutane = [
+    {"suna": "Musa", "shekaru": 30},
+    {"suna": "Aisha", "shekaru": 25},
+    {"suna": "Bello", "shekaru": 30}
+  ]
+  var jere = sort_by(mutane, aiki(m) { mayar m["shekaru"] })
+  var sunaye = map(jere, aiki(m) { mayar m["suna"] })
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(shown(&interpreter, "sunaye"), "[Aisha, Musa, Bello]");
+}
+
+#[test]
+fn test_reduce_empty_list_without_initial_value_is_error() {
+    let error = run("fara\n  var a = reduce([], aiki(a, b) { mayar a + b })\nƙare")
+        .err().expect("Expected an error");
+    assert!(error.to_string().contains("initial value"), "{}", error);
+}
+
+#[test]
+fn test_calling_a_non_function_is_error() {
+    let error = run("fara\n  var x = 5\n  x(1)\nƙare").err().expect("Expected an error");
+    assert!(error.to_string().contains("not a function"), "{}", error);
+}
+
+#[test]
+fn test_lambda_arity_is_checked() {
+    let error = run("fara\n  var f = aiki(a, b) { mayar a + b }\n  f(1)\nƙare")
+        .err().expect("Expected an error");
+    assert!(error.to_string().contains("attend 2 argument(s), mais 1 fourni(s)"), "{}", error);
+}
+
+#[test]
+fn test_reduce_arity_is_checked_by_the_registry() {
+    let error = run("fara\n  var a = reduce([1], aiki(x, y) { mayar x + y }, 0, 1)\nƙare")
+        .err().expect("Expected an error");
+    assert!(error.to_string().contains("'reduce' tana bukata 2 zuwa 3"), "{}", error);
+}
+
+#[test]
+fn test_error_inside_function_does_not_leak_scope() {
+    let mut interpreter = Interpreter::new();
+    let program = parse(tokenize("fara\n  aiki karya_aiki(x) {\n    mayar x + babu\n  }\n  karya_aiki(1)\nƙare").unwrap()).unwrap();
+    assert!(interpreter.execute(program).is_err());
+
+    // Après l'erreur, `var` doit à nouveau créer une variable globale
+    let program = parse(tokenize("fara\n  var a = 1\nƙare").unwrap()).unwrap();
+    interpreter.execute(program).unwrap();
+    assert!(interpreter.get_variable("a").is_some());
+}
@@ -0,0 +1,310 @@
+//! Tests pour les listes et les dictionnaires
+
+mod common;
+
+use dabara::interpreter::Value;
+use common::{run, shown};
+
+#[test]
+fn test_map_literal_and_indexing() {
+    let source = r#"
+fara
+  var mutum = {"suna": "Musa", "shekaru": 20}
+  var suna = mutum["suna"]
+  var shekaru = mutum["shekaru"]
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    assert_eq!(shown(&interpreter, "mutum"), "{suna: Musa, shekaru: 20}");
+    assert_eq!(shown(&interpreter, "suna"), "Musa");
+    match interpreter.get_variable("shekaru") {
+        Some(Value::Number(n)) => assert_eq!(*n, 20),
+        other => panic!("Expected number, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_map_key_assignment_keeps_insertion_order() {
+    let source = r#"
+fara
+  var m = {"b": 1, "a": 2}
+  m["c"] = 3
+  m["b"] = 10
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    assert_eq!(shown(&interpreter, "m"), "{b: 10, a: 2, c: 3}");
+}
+
+#[test]
+fn test_map_methods() {
+    let source = r#"
+fara
+  var m = {"suna": "Musa", 1: gaskiya}
+  var makullai = m.maɓallai()
+  var kimomi = m.ƙimomi()
+  var akwai = m.yana_da("suna")
+  var babu = m.yana_da("gari")
+  var girma = m.tsawo()
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    assert_eq!(shown(&interpreter, "makullai"), "[suna, 1]");
+    assert_eq!(shown(&interpreter, "kimomi"), "[Musa, gaskiya]");
+    assert_eq!(shown(&interpreter, "akwai"), "gaskiya");
+    assert_eq!(shown(&interpreter, "babu"), "karya");
+    assert_eq!(shown(&interpreter, "girma"), "2");
+}
+
+#[test]
+fn test_for_loop_over_map_keys() {
+    let source = r#"
+fara
+  var kudi = {"abinci": 1500, "mota": 800}
+  var jimla = 0
+  var sunaye = ""
+  ga k cikin kudi {
+    var jimla = jimla + kudi[k]
+    var sunaye = sunaye + k
+  }
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    assert_eq!(shown(&interpreter, "jimla"), "2300");
+    assert_eq!(shown(&interpreter, "sunaye"), "abincimota");
+}
+
+#[test]
+fn test_map_and_list_equality() {
+    let source = r#"
+fara
+  var a = {"x": 1, "y": [1, 2]} == {"y": [1, 2], "x": 1}
+  var b = {"x": 1} != {"x": 2}
+  var c = [1, "a"] == [1, "a"]
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    assert_eq!(shown(&interpreter, "a"), "gaskiya");
+    assert_eq!(shown(&interpreter, "b"), "gaskiya");
+    assert_eq!(shown(&interpreter, "c"), "gaskiya");
+}
+
+#[test]
+fn test_map_errors() {
+    assert!(run("fara\n  var m = {\"a\": 1}\n  rubuta m[\"b\"]\nƙare").is_err());
+    assert!(run("fara\n  var m = {[1]: 1}\nƙare").is_err());
+    assert!(run("fara\n  var l = 5\n  l[\"a\"] = 1\nƙare").is_err());
+}
+
+#[test]
+fn test_list_append_mutates_in_place() {
+    let source = r#"
+fara
+  var jeri = [1, 2]
+  var wani = jeri
+  rubuta "wani yana nuna jeri"
+  wani.kara(3)
+  jeri.ƙara(4)
+  wani.kara(5)
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    assert_eq!(shown(&interpreter, "jeri"), "[1, 2, 3, 4, 5]");
+    assert_eq!(shown(&interpreter, "wani"), "[1, 2, 3, 4, 5]");
+}
+
+#[test]
+fn test_list_mutation_through_function_parameter() {
+    let source = r#"
+fara
+  naɗa kudin_shiga = []
+  aiki kara_shiga(jeri, kudi) {
+    jeri.kara(kudi)
+  }
+  aiki kara_duniya(kudi) {
+    kudin_shiga.kara(kudi)
+  }
+  kara_shiga(kudin_shiga, 50000)
+  kara_duniya(25000)
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    assert_eq!(shown(&interpreter, "kudin_shiga"), "[50000, 25000]");
+}
+
+#[test]
+fn test_list_insert_and_remove() {
+    let source = r#"
+fara
+  var jeri = [1, 2, 3]
+  jeri.saka(0, 0)
+  jeri.saka(4, 4)
+  var karshe = jeri.cire()
+  var na_farko = jeri.cire(0)
+  var tsakiya = jeri.cire(-2)
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    assert_eq!(shown(&interpreter, "karshe"), "4");
+    assert_eq!(shown(&interpreter, "na_farko"), "0");
+    assert_eq!(shown(&interpreter, "tsakiya"), "2");
+    assert_eq!(shown(&interpreter, "jeri"), "[1, 3]");
+}
+
+#[test]
+fn test_map_methods_mutate_in_place() {
+    let source = r#"
+fara
+  var mutum = {"suna": "Musa"}
+  aiki sabunta(m) {
+    m.saka("shekaru", 20)
+    m["gari"] = "Kano"
+  }
+  sabunta(mutum)
+  var suna = mutum.cire("suna")
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    assert_eq!(shown(&interpreter, "suna"), "Musa");
+    assert_eq!(shown(&interpreter, "mutum"), "{shekaru: 20, gari: Kano}");
+}
+
+#[test]
+fn test_list_mutation_during_for_loop() {
+    let source = r#"
+fara
+  var jeri = [1, 2, 3]
+  ga abu cikin jeri {
+    jeri.kara(abu * 10)
+  }
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    assert_eq!(shown(&interpreter, "jeri"), "[1, 2, 3, 10, 20, 30]");
+}
+
+#[test]
+fn test_list_index_assignment() {
+    let source = r#"
+fara
+  var jeri = [5, 3, 8, 1]
+  jeri[0] = 10
+  jeri[-1] = 20
+  jeri[1] += 4
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    assert_eq!(shown(&interpreter, "jeri"), "[10, 7, 8, 20]");
+}
+
+#[test]
+fn test_nested_index_assignment() {
+    let source = r#"
+fara
+  var grid = [[1, 2], [3, 4]]
+  var mutane = {"Musa": {"shekaru": 20}}
+  grid[1][0] = 0
+  grid[0][-1] = 9
+  mutane["Musa"]["shekaru"] = 21
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    assert_eq!(shown(&interpreter, "grid"), "[[1, 9], [0, 4]]");
+    assert_eq!(shown(&interpreter, "mutane"), "{Musa: {shekaru: 21}}");
+}
+
+#[test]
+fn test_nested_index_assignment_evaluates_each_part_once_in_order() {
+    let source = r#"
+fara
+  var tarihi = []
+  aiki g() {
+    tarihi.ƙara("g")
+    mayar 1
+  }
+  aiki h() {
+    tarihi.ƙara("h")
+    mayar 0
+  }
+  aiki v() {
+    tarihi.ƙara("v")
+    mayar 10
+  }
+  var grid = [[1, 2], [3, 4]]
+  grid[g()][h()] += v()
+  grid[h()][g()] = v()
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    assert_eq!(shown(&interpreter, "tarihi"), "[g, h, v, h, g, v]");
+    assert_eq!(shown(&interpreter, "grid"), "[[1, 10], [13, 4]]");
+}
+
+#[test]
+fn test_swap_with_index_assignment() {
+    let source = r#"
+fara
+  var jeri = [3, 1, 2]
+  var i = 0
+  maimaita (i < 2) {
+    var j = 0
+    maimaita (j < 2 - i) {
+      idan (jeri[j] > jeri[j + 1]) {
+        var wucin_gadi = jeri[j]
+        jeri[j] = jeri[j + 1]
+        jeri[j + 1] = wucin_gadi
+      }
+      j += 1
+    }
+    i += 1
+  }
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    assert_eq!(shown(&interpreter, "jeri"), "[1, 2, 3]");
+}
+
+#[test]
+fn test_index_assignment_out_of_bounds() {
+    let source = "fara\n  var jeri = [1, 2, 3]\n  jeri[3] = 4\nƙare";
+
+    let error = run(source).err().expect("Expected an error");
+
+    assert!(error.message().contains("ya wuce iyaka"));
+    assert!(error.hint().is_some());
+    assert_eq!(error.span().map(|s| (s.line, s.column)), Some((3, 8)));
+
+    let source = "fara\n  var jeri = [1, 2, 3]\n  jeri[-4] = 0\nƙare";
+    assert!(run(source).is_err());
+
+    let source = "fara\n  var suna = \"Musa\"\n  suna[0] = \"m\"\nƙare";
+    assert!(run(source).is_err());
+}
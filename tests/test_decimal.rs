@@ -0,0 +1,187 @@
+//! Tests pour les nombres décimaux exacts (kuɗi)
+
+mod common;
+
+use dabara::{interpreter::Value, Decimal, ErrorCode, RoundingMode};
+use common::{run, shown};
+
+#[test]
+fn test_decimal_parse_and_display() {
+    let value = Decimal::parse("1,250,000.50").unwrap();
+
+    assert_eq!(value.to_string(), "1,250,000.50");
+    assert_eq!(value.to_plain_string(), "1250000.50");
+    assert_eq!(Decimal::parse("-0.05").unwrap().to_string(), "-0.05");
+    assert_eq!(Decimal::parse("999").unwrap().to_string(), "999");
+    assert_eq!(Decimal::parse("-1234").unwrap().to_string(), "-1,234");
+    assert!(Decimal::parse("12a").is_err());
+    assert!(Decimal::parse("1.2.3").is_err());
+    assert!(Decimal::parse("").is_err());
+}
+
+#[test]
+fn test_decimal_rounding_modes() {
+    let ten = Decimal::from_int(10);
+    let three = Decimal::from_int(3);
+    let value = Decimal::parse("0.125").unwrap();
+
+    assert_eq!(ten.checked_div(&three, 2, RoundingMode::HalfUp).unwrap().to_string(), "3.33");
+    assert_eq!(ten.checked_div(&three, 2, RoundingMode::Up).unwrap().to_string(), "3.34");
+    assert_eq!(value.round(2, RoundingMode::HalfUp).unwrap().to_string(), "0.13");
+    assert_eq!(value.round(2, RoundingMode::HalfEven).unwrap().to_string(), "0.12");
+    assert_eq!(value.round(2, RoundingMode::Down).unwrap().to_string(), "0.12");
+    assert_eq!(Decimal::parse("-0.125").unwrap().round(2, RoundingMode::HalfUp).unwrap().to_string(), "-0.13");
+}
+
+#[test]
+fn test_decimal_exact_arithmetic() {
+    let source = r#"
+fara
+  var a = kudi(0.1) + kudi(0.2)
+  var daidai = a == kudi("0.3")
+  var b = kudi("19.99") * 3
+  var c = kudi(100) - kudi("0.01")
+  var d = kudi(10) / 3
+  var e = kudi("1.50") == kudi("1.5")
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    assert_eq!(shown(&interpreter, "a"), "0.3");
+    assert_eq!(shown(&interpreter, "daidai"), "gaskiya");
+    assert_eq!(shown(&interpreter, "b"), "59.97");
+    assert_eq!(shown(&interpreter, "c"), "99.99");
+    assert_eq!(shown(&interpreter, "d"), "3.33");
+    assert_eq!(shown(&interpreter, "e"), "gaskiya");
+}
+
+#[test]
+fn test_decimal_budget_with_thousands_separators() {
+    let source = r#"
+fara
+  var kudin_shiga = [kudi(50000), kudi(25000)]
+  var kudin_fitowa = [kudi("15000.50"), kudi(8000)]
+  var saura = kudi(0)
+  ga kudi_daya cikin kudin_shiga {
+    saura += kudi_daya
+  }
+  ga kudi_daya cikin kudin_fitowa {
+    saura -= kudi_daya
+  }
+  var sako = "Saura: " + saura + " Naira"
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    assert_eq!(shown(&interpreter, "sako"), "Saura: 51,999.50 Naira");
+}
+
+#[test]
+fn test_decimal_division_with_rounding_mode() {
+    let source = r#"
+fara
+  var a = raba_kudi(kudi(100), 3, 4)
+  var b = raba_kudi(kudi("0.25"), 2, 2, "banki")
+  var c = raba_kudi(kudi("0.25"), 2, 2, "rabi")
+  var d = zagaye_kudi(kudi("2.675"), 2, "ƙasa")
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    assert_eq!(shown(&interpreter, "a"), "33.3333");
+    assert_eq!(shown(&interpreter, "b"), "0.12");
+    assert_eq!(shown(&interpreter, "c"), "0.13");
+    assert_eq!(shown(&interpreter, "d"), "2.67");
+
+    assert!(run("fara\n  var a = raba_kudi(kudi(1), 3, 2, \"babu\")\nƙare").is_err());
+    assert!(run("fara\n  var a = kudi(1) / 0\nƙare").is_err());
+}
+
+#[test]
+fn test_decimal_conversions() {
+    let source = r#"
+fara
+  var a = int(kudi("-7.9"))
+  var b = float(kudi("1.5"))
+  var c = string(kudi("1234.5"))
+  var d = bool(kudi(0))
+  var e = kudi("12.30") > 12
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    match interpreter.get_variable("a") {
+        Some(Value::Number(n)) => assert_eq!(*n, -7),
+        other => panic!("Expected number, got {:?}", other),
+    }
+    match interpreter.get_variable("b") {
+        Some(Value::Float(f)) => assert_eq!(*f, 1.5),
+        other => panic!("Expected float, got {:?}", other),
+    }
+    assert_eq!(shown(&interpreter, "c"), "1,234.5");
+    assert_eq!(shown(&interpreter, "d"), "karya");
+    assert_eq!(shown(&interpreter, "e"), "gaskiya");
+}
+
+#[test]
+fn test_decimal_and_float_do_not_mix() {
+    let error = run("fara\n  var a = kudi(1) + 0.5\nƙare").err().expect("Expected an error");
+
+    assert!(error.message().contains("kuɗi"));
+    assert!(error.hint().is_some());
+}
+
+#[test]
+fn test_decimal_math_and_list_functions() {
+    let source = r#"
+fara
+  var jimla = sum([kudi("19.99"), kudi("0.01"), 5])
+  var cikakke = abs(kudi("-2.50"))
+  var karami = min(kudi("1.50"), 2, kudi("1.25"))
+  var babba = max(kudi("1.50"), 2, kudi("1.25"))
+  var zagaye = round(kudi("2.50"))
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    assert_eq!(shown(&interpreter, "jimla"), "25.00");
+    assert_eq!(shown(&interpreter, "cikakke"), "2.50");
+    assert_eq!(shown(&interpreter, "karami"), "1.25");
+    assert_eq!(shown(&interpreter, "babba"), "2");
+    assert!(matches!(interpreter.get_variable("zagaye"), Some(Value::Number(3))));
+
+    for source in [
+        "fara\n  var a = sum([kudi(1), 0.5])\nƙare",
+        "fara\n  var a = min(kudi(1), 0.5)\nƙare",
+    ] {
+        let error = run(source).err().expect("Expected an error");
+        assert_eq!(error.code(), ErrorCode::DecimalFloatMix, "{}", source);
+    }
+}
+
+#[test]
+fn test_decimal_multiply_beyond_max_scale() {
+    let small = Decimal::parse("0.00000000000001").unwrap();
+
+    let error = small.checked_mul(&small).and_then(|d| d.checked_mul(&small)).expect_err("Expected an error");
+
+    assert_eq!(error.code(), ErrorCode::TooManyDecimalPlaces);
+    assert!(error.message().contains("too many decimal places"));
+    assert!(!error.message().contains("overflow"));
+}
+
+#[test]
+fn test_decimal_functions_check_arity_through_the_registry() {
+    for (source, expected) in [
+        ("fara\n  var a = raba_kudi(kudi(1), 3, 2, \"rabi\", 5)\nƙare", "raba_kudi' tana bukata 3 zuwa 4"),
+        ("fara\n  var a = zagaye_kudi(kudi(1), 2, \"rabi\", 5)\nƙare", "zagaye_kudi' tana bukata 2 zuwa 3"),
+    ] {
+        let error = run(source).err().expect("Expected an error");
+        assert!(error.message().contains(expected), "{}", error.message());
+    }
+}
@@ -0,0 +1,136 @@
+//! Tests pour la localisation et le rendu des erreurs
+
+mod common;
+
+use dabara::{lexer::tokenize, Error, ErrorCode, Span, Token};
+use dabara::error::RenderStyle;
+use common::run_error;
+
+#[test]
+fn test_token_spans() {
+    let tokens = tokenize("fara\n  rubuta \"Sannu\"\nƙare").unwrap();
+
+    assert_eq!(tokens[0].token, Token::Begin);
+    assert_eq!(tokens[0].span, Span::new(1, 1, 4));
+
+    assert_eq!(tokens[2].token, Token::Print);
+    assert_eq!(tokens[2].span, Span::new(2, 3, 6));
+
+    assert_eq!(tokens[3].token, Token::String("Sannu".to_string()));
+    assert_eq!(tokens[3].span, Span::new(2, 10, 7));
+
+    // Les caractères haoussa comptent pour une seule colonne
+    assert_eq!(tokens[5].token, Token::End);
+    assert_eq!(tokens[5].span, Span::new(3, 1, 4));
+}
+
+#[test]
+fn test_lex_error_location() {
+    let error = run_error("fara\n  var x = 1 @\nƙare");
+
+    assert!(matches!(error, Error::LexError(..)));
+    assert_eq!(error.span().map(|s| (s.line, s.column)), Some((2, 13)));
+}
+
+#[test]
+fn test_unterminated_string_points_at_opening_quote() {
+    let error = run_error("fara\n  rubuta \"Sannu\nƙare");
+
+    assert!(matches!(error, Error::LexError(..)));
+    assert_eq!(error.span().map(|s| (s.line, s.column)), Some((2, 10)));
+}
+
+#[test]
+fn test_parse_error_location() {
+    let error = run_error("fara\n  var x = (1 + 2\nƙare");
+
+    assert!(matches!(error, Error::ParseError(..)));
+    assert_eq!(error.span().map(|s| (s.line, s.column)), Some((3, 1)));
+}
+
+#[test]
+fn test_runtime_error_location() {
+    let error = run_error("fara\n  var x = 1\n  rubuta x + babu\nƙare");
+
+    assert!(matches!(error, Error::RuntimeError(..)));
+    assert_eq!(error.span().map(|s| (s.line, s.column)), Some((3, 14)));
+    assert!(error.to_string().contains("layi 3"));
+}
+
+#[test]
+fn test_runtime_error_inside_function_keeps_inner_location() {
+    let source = r#"fara
+  aiki raba_da_sifili(a) {
+    mayar a / 0
+  }
+  var x = raba_da_sifili(5)
+ƙare"#;
+
+    let error = run_error(source);
+
+    assert_eq!(error.span().map(|s| (s.line, s.column)), Some((3, 13)));
+}
+
+#[test]
+fn test_render_plain_diagnostic() {
+    let source = "fara\n  var x = 1\n  rubuta x + babu\nƙare";
+    let error = run_error(source);
+
+    let rendered = error.render(source, "gwaji.ha", RenderStyle::Plain);
+    let lines: Vec<&str> = rendered.lines().collect();
+
+    assert_eq!(lines[0], "Kuskure na Runtime: Babu irin wannan mai canjin 'babu' da aka rubuta");
+    assert_eq!(lines[1], " --> gwaji.ha:3:14");
+    assert_eq!(lines[3], "3 |   rubuta x + babu");
+    assert_eq!(lines[4], "  |              ^^^^");
+    assert!(lines[5].contains("shawara"));
+    assert!(!rendered.contains('\x1b'));
+}
+
+#[test]
+fn test_render_color_diagnostic() {
+    let source = "fara\n  rubuta \"Sannu\nƙare";
+    let error = run_error(source);
+
+    let rendered = error.render(source, "gwaji.ha", RenderStyle::Color);
+
+    assert!(rendered.contains("\x1b[1;31mKuskure na Tokenization\x1b[0m"));
+    assert!(rendered.contains("Ka rufe jimlar"));
+}
+
+#[test]
+fn test_render_without_location() {
+    let error = Error::file_not_found("babu.ha");
+
+    let rendered = error.render("", "babu.ha", RenderStyle::Plain);
+
+    assert_eq!(rendered, "Kuskure na Fayil: Ba za a iya samun fayil 'babu.ha' ba");
+}
+
+#[test]
+fn test_error_codes_choose_hints() {
+    let cases = [
+        ("fara\n  rubuta \"Sannu\nƙare", ErrorCode::UnterminatedString),
+        ("rubuta 1\nƙare", ErrorCode::MissingBegin),
+        ("fara\n  rubuta (1 + 2\n", ErrorCode::UnexpectedEof),
+        ("fara\n  rubuta babu\nƙare", ErrorCode::VariableNotFound),
+        ("fara\n  babu()\nƙare", ErrorCode::FunctionNotFound),
+        ("fara\n  rubuta 1 / 0\nƙare", ErrorCode::DivisionByZero),
+        ("fara\n  rubuta kudi(1.5) + 0.5\nƙare", ErrorCode::DecimalFloatMix),
+        ("fara\n  var jeri = [1, 2]\n  rubuta jeri[5]\nƙare", ErrorCode::IndexOutOfBounds),
+    ];
+
+    for (source, code) in cases {
+        let error = run_error(source);
+        assert_eq!(error.code(), code, "{}", source);
+        assert!(error.hint().is_some(), "{}", source);
+    }
+}
+
+#[test]
+fn test_generic_errors_have_no_hint() {
+    let error = Error::runtime_error("Babu irin wannan mai canjin 'x' da aka rubuta");
+
+    assert_eq!(error.code(), ErrorCode::Generic);
+    assert_eq!(error.hint(), None);
+}
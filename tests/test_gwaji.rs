@@ -0,0 +1,180 @@
+//! Tests pour tabbatar, les blocs gwaji et leur exécution (`dabara test`)
+
+mod common;
+
+use dabara::{lexer::tokenize, parser::parse, Error, Program};
+use dabara::testing::{find_test_files, run_tests};
+use std::fs;
+use common::run_with_output;
+
+/// Analyse un programme
+fn program(source: &str) -> Program {
+    parse(tokenize(source).unwrap()).unwrap()
+}
+
+#[test]
+fn test_passing_assertion_does_nothing() {
+    let output = run_with_output("fara\ntabbatar 1 + 1 == 2\ntabbatar gaskiya, \"ba za a gani ba\"\nrubuta \"lafiya\"\nƙare").unwrap();
+    assert_eq!(output, "lafiya\n");
+}
+
+#[test]
+fn test_failing_assertion_shows_compared_values() {
+    let error = run_with_output("fara\nvar x = 3\ntabbatar x + 1 == 5\nƙare").expect_err("Expected an error");
+
+    assert!(matches!(error, Error::RuntimeError(..)));
+    assert_eq!(error.message(), "Tabbatarwa ta kasa: 4 == 5 (assertion failed)");
+    assert_eq!(error.span().map(|s| (s.line, s.column)), Some((3, 1)));
+}
+
+#[test]
+fn test_failing_assertion_with_message() {
+    let error = run_with_output("fara\nvar suna = \"Musa\"\ntabbatar suna == \"Aisha\", \"suna bai dace ba\"\nƙare")
+        .expect_err("Expected an error");
+    assert_eq!(error.message(), "Tabbatarwa ta kasa: suna bai dace ba: \"Musa\" == \"Aisha\" (assertion failed)");
+
+    let error = run_with_output("fara\ntabbatar karya ko ba gaskiya, \"jeri \" + \"babu komai\"\nƙare")
+        .expect_err("Expected an error");
+    assert_eq!(error.message(), "Tabbatarwa ta kasa: jeri babu komai (assertion failed)");
+}
+
+#[test]
+fn test_failing_assertion_without_message_has_hint() {
+    let error = run_with_output("fara\ntabbatar karya\nƙare").expect_err("Expected an error");
+
+    assert_eq!(error.message(), "Tabbatarwa ta kasa (assertion failed)");
+    assert!(error.hint().is_some());
+}
+
+#[test]
+fn test_assertion_failure_can_be_caught() {
+    let output = run_with_output("fara
+gwada {
+    tabbatar 1 > 2
+} kama kuskure {
+    rubuta kuskure.saƙo()
+}
+ƙare").unwrap();
+
+    assert_eq!(output, "Tabbatarwa ta kasa: 1 > 2 (assertion failed)\n");
+}
+
+#[test]
+fn test_test_blocks_are_skipped_by_ordinary_runs() {
+    let output = run_with_output("fara
+rubuta \"kafin\"
+gwaji \"ba zai gudana ba\" {
+    rubuta \"cikin gwaji\"
+    tabbatar karya
+}
+rubuta \"bayan\"
+ƙare").unwrap();
+
+    assert_eq!(output, "kafin\nbayan\n");
+}
+
+#[test]
+fn test_test_blocks_must_be_at_top_level() {
+    let error = tokenize("fara\naiki f() {\n    gwaji \"a\" {\n    }\n}\nƙare").and_then(parse)
+        .expect_err("Expected a syntax error");
+
+    assert!(matches!(error, Error::ParseError(..)));
+    assert!(error.message().contains("top level"), "{}", error.message());
+    assert_eq!(error.span().map(|s| (s.line, s.column)), Some((3, 5)));
+}
+
+#[test]
+fn test_test_block_needs_a_name() {
+    let error = tokenize("fara\ngwaji {\n}\nƙare").and_then(parse).expect_err("Expected a syntax error");
+    assert!(matches!(error, Error::ParseError(..)));
+}
+
+#[test]
+fn test_run_tests_reports_each_block() {
+    let outcomes = run_tests(&program("fara
+aiki ninka(a, b) {
+    mayar a * b
+}
+gwaji \"ninka\" {
+    tabbatar ninka(3, 4) == 12
+}
+gwaji \"kuskure\" {
+    tabbatar ninka(2, 2) == 5, \"ba daidai ba\"
+}
+ƙare"), |_| {});
+
+    let results: Vec<(&str, bool)> = outcomes.iter().map(|o| (o.name.as_str(), o.passed())).collect();
+    assert_eq!(results, vec![("ninka", true), ("kuskure", false)]);
+    assert_eq!((outcomes[1].span.line, outcomes[1].span.column), (8, 1));
+
+    let error = outcomes[1].error.as_ref().unwrap();
+    assert_eq!(error.message(), "Tabbatarwa ta kasa: ba daidai ba: 4 == 5 (assertion failed)");
+    assert_eq!(error.span().map(|s| s.line), Some(9));
+}
+
+#[test]
+fn test_each_block_runs_in_a_fresh_interpreter() {
+    let outcomes = run_tests(&program("fara
+var ƙidaya = 0
+rubuta \"shiri\"
+gwaji \"na farko\" {
+    ƙidaya = ƙidaya + 1
+    tabbatar ƙidaya == 1
+}
+gwaji \"na biyu\" {
+    ƙidaya = ƙidaya + 1
+    tabbatar ƙidaya == 1
+    rubuta ƙidaya
+}
+ƙare"), |_| {});
+
+    assert!(outcomes.iter().all(|outcome| outcome.passed()));
+    assert_eq!(outcomes[0].output, "shiri\n");
+    assert_eq!(outcomes[1].output, "shiri\n1\n");
+}
+
+#[test]
+fn test_run_tests_applies_configuration() {
+    let outcomes = run_tests(&program("fara
+gwaji \"madauki\" {
+    maimaita (gaskiya) {
+    }
+}
+ƙare"), |interpreter| interpreter.set_step_limit(Some(100)));
+
+    assert!(matches!(outcomes[0].error, Some(Error::LimitExceeded(..))));
+}
+
+#[test]
+fn test_input_in_tests_is_end_of_input() {
+    let outcomes = run_tests(&program("fara\ngwaji \"karɓa\" {\n    var x = karɓa\n}\nƙare"), |_| {});
+    assert!(outcomes[0].error.as_ref().is_some_and(|e| e.message().contains("end of input")));
+}
+
+#[test]
+fn test_find_test_files_recurses_and_sorts() {
+    let root = std::env::temp_dir().join(format!("dabara_gwaji_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("b")).unwrap();
+    fs::create_dir_all(root.join(".boye")).unwrap();
+    for file in ["z_test.ha", "a_test.ha", "shiri.ha", "b/c_test.ha", ".boye/d_test.ha"] {
+        fs::write(root.join(file), "fara\nƙare").unwrap();
+    }
+
+    let files = find_test_files(&root).unwrap();
+    let _ = fs::remove_dir_all(&root);
+
+    let names: Vec<String> = files.iter()
+        .map(|path| path.strip_prefix(&root).unwrap().to_string_lossy().replace('\\', "/"))
+        .collect();
+    assert_eq!(names, vec!["a_test.ha", "b/c_test.ha", "z_test.ha"]);
+}
+
+#[test]
+fn test_example_test_file_passes() {
+    let source = fs::read_to_string("examples/lissafi_test.ha").unwrap();
+    let outcomes = run_tests(&program(&source), |_| {});
+
+    assert_eq!(outcomes.len(), 3);
+    assert!(outcomes.iter().all(|outcome| outcome.passed()), "{:?}", outcomes);
+}
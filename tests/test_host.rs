@@ -0,0 +1,194 @@
+//! Tests pour les entrées et sorties des programmes (trait Host)
+
+mod common;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use dabara::host::{BufferHost, Host, ScriptedHost};
+use dabara::{lexer::tokenize, parser::parse, interpreter::Interpreter, Error};
+use common::run_with_input;
+
+#[test]
+fn test_print_goes_to_host() {
+    let output = run_with_input("fara\nrubuta \"Sannu\"\nrubuta 1 + 2\nƙare", &[]).unwrap();
+    assert_eq!(output, "Sannu\n3\n");
+}
+
+#[test]
+fn test_print_inside_functions_and_loops_goes_to_host() {
+    let output = run_with_input("fara
+aiki gaisuwa(suna) {
+    rubuta \"Sannu \" + suna
+}
+ga suna cikin [\"Musa\", \"Aisha\"] {
+    gaisuwa(suna)
+}
+gwada {
+    jefa \"kuskure\"
+} kama e {
+    rubuta e.saƙo()
+}
+ƙare", &[]).unwrap();
+
+    assert_eq!(output, "Sannu Musa\nSannu Aisha\nkuskure\n");
+}
+
+#[test]
+fn test_input_reads_from_host() {
+    let output = run_with_input("fara
+var suna = karɓa
+var shekaru = karɓa
+rubuta suna
+rubuta shekaru + 1
+ƙare", &["Musa", "  41 "]).unwrap();
+
+    assert_eq!(output, "Rubuta abu: Rubuta abu: Musa\n42\n");
+}
+
+#[test]
+fn test_input_at_end_of_input_is_an_error() {
+    let error = run_with_input("fara\nvar abu = karɓa\nƙare", &[]).expect_err("Expected an error");
+    assert!(error.message().contains("end of input"), "{}", error.message());
+    assert!(error.is_catchable());
+}
+
+/// Host qui sépare les invites de la sortie ordinaire
+#[derive(Default, Clone)]
+struct RecordingHost {
+    written: Rc<RefCell<Vec<String>>>,
+    prompts: Rc<RefCell<Vec<String>>>,
+}
+
+impl Host for RecordingHost {
+    fn write(&mut self, text: &str) {
+        self.written.borrow_mut().push(text.to_string());
+    }
+
+    fn prompt(&mut self, text: &str) {
+        self.prompts.borrow_mut().push(text.to_string());
+    }
+
+    fn read_line(&mut self) -> Result<Option<String>, Error> {
+        Ok(Some("3.5".to_string()))
+    }
+}
+
+#[test]
+fn test_custom_host_receives_prompts_separately() {
+    let host = RecordingHost::default();
+    let mut interpreter = Interpreter::new();
+    interpreter.set_host(Box::new(host.clone()));
+
+    let program = parse(tokenize("fara\nvar x = karɓa\nrubuta x * 2\nƙare").unwrap()).unwrap();
+    interpreter.execute(program).unwrap();
+
+    assert_eq!(*host.prompts.borrow(), vec!["Rubuta abu: ".to_string()]);
+    assert_eq!(*host.written.borrow(), vec!["7\n".to_string()]);
+}
+
+#[test]
+fn test_read_errors_propagate() {
+    struct BrokenHost;
+
+    impl Host for BrokenHost {
+        fn write(&mut self, _text: &str) {}
+
+        fn read_line(&mut self) -> Result<Option<String>, Error> {
+            Err(Error::runtime_error("an katse shigarwa"))
+        }
+    }
+
+    let mut interpreter = Interpreter::new();
+    interpreter.set_host(Box::new(BrokenHost));
+    let program = parse(tokenize("fara\nvar x = karɓa\nƙare").unwrap()).unwrap();
+    let error = interpreter.execute(program).expect_err("Expected an error");
+
+    assert_eq!(error.message(), "an katse shigarwa");
+    assert_eq!(error.span().map(|s| s.line), Some(2));
+}
+
+#[test]
+fn test_execute_with_output_restores_host() {
+    let host = BufferHost::new();
+    let mut interpreter = Interpreter::new();
+    interpreter.set_host(Box::new(host.clone()));
+
+    let mut captured = String::new();
+    let program = parse(tokenize("fara\nrubuta \"ɗaya\"\nƙare").unwrap()).unwrap();
+    interpreter.execute_with_output(program, &mut captured).unwrap();
+    assert_eq!(captured, "ɗaya\n");
+    assert_eq!(host.output(), "");
+
+    let program = parse(tokenize("fara\nrubuta \"biyu\"\nƙare").unwrap()).unwrap();
+    interpreter.execute(program).unwrap();
+    assert_eq!(host.output(), "biyu\n");
+}
+
+#[test]
+fn test_buffer_host_take_output_and_push_input() {
+    let host = BufferHost::new();
+    let mut interpreter = Interpreter::new();
+    interpreter.set_host(Box::new(host.clone()));
+
+    host.push_input(["na farko"]);
+    let program = parse(tokenize("fara\nrubuta karɓa\nƙare").unwrap()).unwrap();
+    interpreter.execute(program).unwrap();
+
+    assert_eq!(host.take_output(), "Rubuta abu: na farko\n");
+    assert_eq!(host.output(), "");
+}
+
+#[test]
+fn test_scripted_input_feeds_reads_in_order() {
+    let output = BufferHost::new();
+    let host = ScriptedHost::new(output.clone(), ["Musa", "41"]);
+    let mut interpreter = Interpreter::new();
+    interpreter.set_host(Box::new(host));
+
+    let program = parse(tokenize("fara\nvar suna = karɓa(\"Suna? \")\nvar shekaru = karɓa_lamba()\nrubuta suna + \" \" + (shekaru + 1)\nƙare").unwrap()).unwrap();
+    interpreter.execute(program).unwrap();
+
+    assert_eq!(output.output(), "Suna? Rubuta abu: Musa 42\n");
+}
+
+#[test]
+fn test_scripted_input_transcript_echoes_answers() {
+    let output = BufferHost::new();
+    let host = ScriptedHost::from_text(output.clone(), "goma\r\n10\r\n").with_transcript(true);
+    let mut interpreter = Interpreter::new();
+    interpreter.set_host(Box::new(host));
+
+    let program = parse(tokenize("fara\nrubuta karɓa_lamba(\"Lamba? \") * 2\nƙare").unwrap()).unwrap();
+    interpreter.execute(program).unwrap();
+
+    assert_eq!(output.output(), "Lamba? goma
+Wannan ba lamba ba ce, sake rubutawa (not a number, try again)
+Lamba? 10
+20
+");
+}
+
+#[test]
+fn test_scripted_input_exhausted_is_a_clear_error() {
+    let host = ScriptedHost::new(BufferHost::new(), ["Musa"]);
+    let mut interpreter = Interpreter::new();
+    interpreter.set_host(Box::new(host));
+
+    let program = parse(tokenize("fara\nvar a = karɓa\nvar b = karɓa\nƙare").unwrap()).unwrap();
+    let error = interpreter.execute(program).expect_err("Expected an error");
+
+    assert!(error.message().contains("scripted input exhausted, 1 lines read"), "{}", error.message());
+    assert!(error.hint().is_some_and(|hint| hint.contains("--input")));
+    assert_eq!(error.span().map(|s| (s.line, s.column)), Some((3, 9)));
+}
+
+#[test]
+fn test_scripted_host_counts_remaining_lines() {
+    let mut host = ScriptedHost::new(BufferHost::new(), ["a", "b", "c"]);
+    assert_eq!(host.remaining(), 3);
+
+    assert_eq!(host.read_line().unwrap().as_deref(), Some("a"));
+    assert_eq!(host.remaining(), 2);
+    assert_eq!(host.inner().output(), "");
+}
@@ -0,0 +1,134 @@
+//! Tests pour karɓa avec invite et les variantes typées (karɓa_lamba, karɓa_jimla, karɓa_ee)
+
+mod common;
+
+use dabara::{lexer::tokenize, parser::parse, Error};
+use common::run_with_input;
+
+#[test]
+fn test_input_with_custom_prompt() {
+    let output = run_with_input("fara
+var suna = karɓa(\"Menene sunanka? \")
+rubuta \"Sannu \" + suna
+ƙare", &["Aisha"]).unwrap();
+
+    assert_eq!(output, "Menene sunanka? Sannu Aisha\n");
+}
+
+#[test]
+fn test_input_prompt_can_be_any_expression() {
+    let output = run_with_input("fara
+var lamba = 2
+var amsa = karɓa(\"Tambaya ta \" + lamba + \": \")
+rubuta amsa
+ƙare", &["ee"]).unwrap();
+
+    assert_eq!(output, "Tambaya ta 2: ee\n");
+}
+
+#[test]
+fn test_input_without_prompt_keeps_default() {
+    assert_eq!(run_with_input("fara\nrubuta karɓa()\nƙare", &["x"]).unwrap(), "Rubuta abu: x\n");
+    assert_eq!(run_with_input("fara\nrubuta karɓa\nƙare", &["x"]).unwrap(), "Rubuta abu: x\n");
+}
+
+#[test]
+fn test_input_accepts_a_single_prompt() {
+    let error = tokenize("fara\nvar x = karɓa(\"a\", \"b\")\nƙare").and_then(parse).expect_err("Expected an error");
+    assert!(matches!(error, Error::ParseError(..)));
+}
+
+#[test]
+fn test_read_text_keeps_text_as_typed() {
+    let output = run_with_input("fara
+var lamba = karɓa_jimla(\"Lambar ɗalibi: \")
+rubuta lamba
+rubuta lamba.tsawo()
+var tsoho = karɓa(\"Kuma? \")
+rubuta tsoho
+ƙare", &["007", "007"]).unwrap();
+
+    // karɓa yana ɗaukar "007" a matsayin lamba 7; karɓa_jimla yana barin rubutu yadda yake
+    assert_eq!(output, "Lambar ɗalibi: 007\n3\nKuma? 7\n");
+}
+
+#[test]
+fn test_read_number_prompts_again_until_valid() {
+    let output = run_with_input("fara
+var shekaru = karɓa_lamba(\"Shekarunka? \")
+rubuta shekaru + 1
+ƙare", &["goma", "", "10"]).unwrap();
+
+    assert_eq!(output, "Shekarunka? Wannan ba lamba ba ce, sake rubutawa (not a number, try again)
+Shekarunka? Wannan ba lamba ba ce, sake rubutawa (not a number, try again)
+Shekarunka? 11
+");
+}
+
+#[test]
+fn test_read_number_returns_floats() {
+    let output = run_with_input("fara\nrubuta karɓa_lamba() * 2\nƙare", &[" 2.5 "]).unwrap();
+    assert_eq!(output, "Rubuta abu: 5\n");
+
+    let output = run_with_input("fara\nrubuta karɓa_lamba(\"? \")\nƙare", &["inf", "NaN", "-3"]).unwrap();
+    assert!(output.ends_with("? -3\n"), "{}", output);
+}
+
+#[test]
+fn test_read_yes_no() {
+    let source = "fara
+var amsoshi = []
+ga i cikin 0..6 {
+    amsoshi.ƙara(karɓa_ee(\"Ka yarda? \"))
+}
+rubuta amsoshi
+ƙare";
+    let output = run_with_input(source, &["ee", "A'a", "YES", "no", "a’a", "gaskiya"]).unwrap();
+
+    assert!(output.ends_with("[gaskiya, karya, gaskiya, karya, karya, gaskiya]\n"), "{}", output);
+}
+
+#[test]
+fn test_read_yes_no_prompts_again_in_hausa() {
+    let output = run_with_input("fara\nrubuta karba_ee(\"Ka gama? \")\nƙare", &["watakila", "ee"]).unwrap();
+    assert_eq!(output, "Ka gama? Amsa da 'ee' ko 'a'a' (answer yes or no)\nKa gama? gaskiya\n");
+}
+
+#[test]
+fn test_end_of_input_is_catchable() {
+    let output = run_with_input("fara
+gwada {
+    var lamba = karɓa_lamba(\"Lamba? \")
+} kama kuskure {
+    rubuta \"Babu shigarwa: \" + kuskure.saƙo()
+}
+ƙare", &["ba lamba ba"]).unwrap();
+
+    assert!(output.contains("Babu shigarwa: Shigarwa ta ƙare"), "{}", output);
+}
+
+#[test]
+fn test_end_of_input_error_points_at_call() {
+    for call in ["karɓa", "karɓa(\"? \")", "karɓa_lamba()", "karɓa_jimla()", "karɓa_ee()"] {
+        let source = format!("fara\nvar x = {}\nƙare", call);
+        let error = run_with_input(&source, &[]).expect_err("Expected an error");
+
+        assert!(matches!(error, Error::RuntimeError(..)), "{}", call);
+        assert!(error.message().contains("end of input"), "{}", error.message());
+        assert_eq!(error.span().map(|s| (s.line, s.column)), Some((2, 9)), "{}", call);
+    }
+}
+
+#[test]
+fn test_typed_input_accepts_at_most_one_prompt() {
+    let error = run_with_input("fara\nvar x = karɓa_jimla(\"a\", \"b\")\nƙare", &["x"]).expect_err("Expected an error");
+    assert!(error.message().contains("karɓa_jimla"), "{}", error.message());
+    assert!(error.message().contains("0 zuwa 1"), "{}", error.message());
+}
+
+#[test]
+fn test_typed_input_errors_have_no_call_frame() {
+    let error = run_with_input("fara\nvar x = karɓa_lamba()\nƙare", &[]).expect_err("Expected an error");
+    assert!(error.message().contains("end of input"), "{}", error.message());
+    assert!(error.traceback().is_empty(), "{:?}", error.traceback());
+}
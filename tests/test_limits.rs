@@ -0,0 +1,350 @@
+//! Tests pour les limites d'exécution : profondeur des appels, imbrication du code, budget de pas et délai
+
+mod common;
+
+use dabara::{lexer::tokenize, parser::parse, interpreter::Interpreter, Error};
+use dabara::interpreter::{BROWSER_MAX_CALL_DEPTH, DEFAULT_MAX_CALL_DEPTH};
+use dabara::parser::MAX_NESTING_DEPTH;
+use std::time::{Duration, Instant};
+use common::{with_large_stack, with_small_stack};
+
+/// Exécute un programme avec une profondeur d'appels donnée
+fn run_with_depth(source: &str, depth: usize) -> Result<Interpreter, Error> {
+    let mut interpreter = Interpreter::new();
+    interpreter.set_max_call_depth(depth);
+    interpreter.execute(parse(tokenize(source)?)?)?;
+    Ok(interpreter)
+}
+
+/// Programme qui s'appelle `n` fois avant de s'arrêter
+fn countdown(n: usize) -> String {
+    format!("fara
+aiki kirga(n) {{
+    idan n == 0 {{
+        mayar 0
+    }}
+    mayar kirga(n - 1)
+}}
+var sakamako = kirga({})
+ƙare", n)
+}
+
+#[test]
+fn test_default_max_call_depth() {
+    assert_eq!(Interpreter::new().max_call_depth(), DEFAULT_MAX_CALL_DEPTH);
+}
+
+#[test]
+fn test_configured_call_depth_allows_calls_up_to_the_limit() {
+    // kirga(19) fait 20 appels imbriqués
+    let interpreter = run_with_depth(&countdown(19), 20).expect("20 calls fit in the limit");
+    assert_eq!(interpreter.get_variable("sakamako").map(|v| v.to_string()).as_deref(), Some("0"));
+
+    let error = run_with_depth(&countdown(20), 20).err().expect("21 calls exceed the limit");
+    assert!(matches!(error, Error::RuntimeError(..)));
+    assert!(error.message().starts_with("Kira ya yi zurfi da yawa"), "{}", error.message());
+    assert!(error.message().contains("'kirga'"));
+    assert!(error.message().contains("20"));
+    assert_eq!(error.traceback().len(), 20);
+}
+
+#[test]
+fn test_browser_call_depth_fits_a_small_stack() {
+    // 1000 appels déborderaient la pile: la limite du navigateur doit arrêter la récursion avant
+    let error = with_small_stack(|| {
+        run_with_depth(&countdown(1000), BROWSER_MAX_CALL_DEPTH).err().map(|error| error.message().to_string())
+    }).expect("Expected the call depth limit");
+    assert!(error.starts_with("Kira ya yi zurfi da yawa"), "{}", error);
+
+    let result = with_small_stack(|| {
+        run_with_depth(&countdown(BROWSER_MAX_CALL_DEPTH - 1), BROWSER_MAX_CALL_DEPTH).is_ok()
+    });
+    assert!(result, "Recursion up to the browser limit fits a small stack");
+}
+
+#[test]
+fn test_infinite_recursion_is_a_friendly_error() {
+    let error = run_with_depth("fara
+aiki har_abada() {
+    mayar har_abada()
+}
+har_abada()
+ƙare", 20).err().expect("Expected an error");
+
+    assert!(error.message().contains("recursion too deep"));
+    assert!(error.hint().is_some_and(|hint| hint.contains("yanayin tsayawa")));
+    assert_eq!(error.span().map(|s| (s.line, s.column)), Some((3, 11)));
+}
+
+#[test]
+fn test_recursion_error_can_be_caught() {
+    let interpreter = run_with_depth("fara
+aiki har_abada() {
+    mayar har_abada()
+}
+var saƙo = \"\"
+gwada {
+    har_abada()
+} kama e {
+    saƙo = e.saƙo()
+}
+ƙare", 20).expect("The error is caught");
+
+    let message = interpreter.get_variable("saƙo").map(|v| v.to_string()).unwrap_or_default();
+    assert!(message.starts_with("Kira ya yi zurfi da yawa"), "{}", message);
+}
+
+#[test]
+fn test_mutual_recursion_counts_every_call() {
+    let error = run_with_depth("fara
+aiki ping(n) {
+    mayar pong(n + 1)
+}
+aiki pong(n) {
+    mayar ping(n + 1)
+}
+ping(0)
+ƙare", 30).err().expect("Expected an error");
+
+    assert_eq!(error.traceback().len(), 30);
+    assert!(error.message().contains("'ping'") || error.message().contains("'pong'"));
+}
+
+#[test]
+fn test_callbacks_count_towards_call_depth() {
+    // map et la fonction anonyme comptent chacun pour un appel
+    let error = run_with_depth("fara
+aiki zurfi(n) {
+    mayar map([n], aiki(x) { mayar zurfi(x + 1) })
+}
+zurfi(0)
+ƙare", 30).err().expect("Expected an error");
+
+    assert_eq!(error.traceback().len(), 30);
+}
+
+/// Code imbriqué sur `levels` niveaux de parenthèses
+fn nested_parentheses(levels: usize) -> String {
+    format!("fara\nvar x = {}1{}\nƙare", "(".repeat(levels), ")".repeat(levels))
+}
+
+#[test]
+fn test_nested_expressions_within_limit() {
+    let x = with_large_stack(|| {
+        let interpreter = run_with_depth(&nested_parentheses(MAX_NESTING_DEPTH - 2), DEFAULT_MAX_CALL_DEPTH)
+            .expect("Nesting within the limit parses");
+        interpreter.get_variable("x").map(|v| v.to_string())
+    });
+    assert_eq!(x.as_deref(), Some("1"));
+}
+
+#[test]
+fn test_deeply_nested_expression_is_a_syntax_error() {
+    let source = nested_parentheses(100_000);
+    let error = with_large_stack(move || tokenize(&source).and_then(parse).expect_err("Expected a syntax error"));
+
+    assert!(matches!(error, Error::ParseError(..)));
+    assert!(error.message().contains("nesting too deep"), "{}", error.message());
+    assert!(error.hint().is_some());
+    assert_eq!(error.span().map(|s| s.line), Some(2));
+}
+
+#[test]
+fn test_deeply_nested_unary_and_power_are_syntax_errors() {
+    for expression in ["-".repeat(10_000) + "1", ["2"; 10_000].join(" ** "), "ba ".repeat(10_000) + "gaskiya"] {
+        let source = format!("fara\nvar x = {}\nƙare", expression);
+        let error = with_large_stack(move || tokenize(&source).and_then(parse).expect_err("Expected a syntax error"));
+        assert!(matches!(error, Error::ParseError(..)), "{}", error);
+    }
+}
+
+#[test]
+fn test_long_flat_operator_chains_are_syntax_errors() {
+    // Sans parenthèses, mais chaque maillon ajoute un niveau à l'arbre: pas besoin d'une grande pile
+    for expression in [
+        ["1"; 20_000].join(" + "),
+        ["2"; 20_000].join(" * "),
+        ["gaskiya"; 20_000].join(" da "),
+        ["karya"; 20_000].join(" ko "),
+        ["1"; 20_000].join(" == "),
+        format!("\"a\"{}", ".babba()".repeat(20_000)),
+        format!("jeri{}", "[0]".repeat(20_000)),
+    ] {
+        let source = format!("fara\nvar x = {}\nƙare", expression);
+        let error = tokenize(&source).and_then(parse).expect_err("Expected a syntax error");
+        assert!(error.message().contains("nesting too deep"), "{}", error.message());
+    }
+}
+
+#[test]
+fn test_flat_operator_chain_within_limit_runs() {
+    let source = format!("fara\nvar x = {}\nƙare", ["1"; 50].join(" + "));
+    let interpreter = run_with_depth(&source, DEFAULT_MAX_CALL_DEPTH).expect("A 50-term sum parses and runs");
+    assert_eq!(interpreter.get_variable("x").map(|v| v.to_string()).as_deref(), Some("50"));
+}
+
+#[test]
+fn test_deeply_nested_blocks_are_a_syntax_error() {
+    let levels = 10_000;
+    let source = format!(
+        "fara\n{}rubuta 1\n{}ƙare",
+        "idan gaskiya {\n".repeat(levels),
+        "}\n".repeat(levels)
+    );
+    let error = with_large_stack(move || tokenize(&source).and_then(parse).expect_err("Expected a syntax error"));
+
+    assert!(error.message().contains("nesting too deep"), "{}", error.message());
+}
+
+#[test]
+fn test_nested_blocks_within_limit_run() {
+    let levels = MAX_NESTING_DEPTH / 2;
+    let source = format!(
+        "fara\nvar n = 0\n{}n = n + 1\n{}ƙare",
+        "idan gaskiya {\n".repeat(levels),
+        "}\n".repeat(levels)
+    );
+
+    let n = with_large_stack(move || {
+        let interpreter = run_with_depth(&source, DEFAULT_MAX_CALL_DEPTH).expect("Nesting within the limit runs");
+        interpreter.get_variable("n").map(|v| v.to_string())
+    });
+    assert_eq!(n.as_deref(), Some("1"));
+}
+
+/// Exécute un programme avec un budget de pas et un délai
+fn run_with_limits(source: &str, steps: Option<u64>, time: Option<Duration>) -> Result<Interpreter, Error> {
+    let mut interpreter = Interpreter::new();
+    interpreter.set_step_limit(steps);
+    interpreter.set_time_limit(time);
+    interpreter.execute(parse(tokenize(source)?)?)?;
+    Ok(interpreter)
+}
+
+const ENDLESS_LOOP: &str = "fara
+var i = 0
+maimaita (gaskiya) {
+    i = i + 1
+}
+ƙare";
+
+#[test]
+fn test_step_budget_stops_endless_loop() {
+    let error = run_with_limits(ENDLESS_LOOP, Some(1000), None).err().expect("Expected an error");
+
+    assert!(matches!(error, Error::LimitExceeded(..)));
+    assert_eq!(error.kind(), "Iyaka");
+    assert!(error.message().contains("step budget exhausted"), "{}", error.message());
+    assert!(error.message().contains("1000"));
+    assert_eq!(error.span().map(|s| (s.line, s.column)), Some((3, 1)));
+    assert!(error.hint().is_some());
+}
+
+#[test]
+fn test_step_budget_counts_iterations_and_calls() {
+    let source = "fara
+aiki ɗaya() {
+    mayar 1
+}
+var jimla = 0
+ga i cikin 0..10 {
+    jimla = jimla + ɗaya()
+}
+ƙare";
+
+    // 10 tours de boucle et 10 appels
+    let interpreter = run_with_limits(source, Some(20), None).expect("20 steps are enough");
+    assert_eq!(interpreter.steps(), 20);
+
+    let error = run_with_limits(source, Some(19), None).err().expect("19 steps are not enough");
+    assert!(matches!(error, Error::LimitExceeded(..)));
+}
+
+#[test]
+fn test_step_budget_stops_endless_recursion_in_a_loop() {
+    let error = run_with_limits("fara
+aiki kullum(n) {
+    mayar n
+}
+ga i cikin 0..1000000000000 {
+    kullum(i)
+}
+ƙare", Some(5000), None).err().expect("Expected an error");
+
+    assert!(matches!(error, Error::LimitExceeded(..)));
+}
+
+#[test]
+fn test_step_budget_stops_callbacks_over_huge_ranges() {
+    for call in [
+        "map(0..1000000000000, aiki(x) { mayar x })",
+        "filter(0..1000000000000, aiki(x) { mayar gaskiya })",
+        "reduce(0..1000000000000, aiki(a, x) { mayar a + x }, 0)",
+        "sort_by(0..1000000000000, aiki(x) { mayar x })",
+    ] {
+        let source = format!("fara\nvar a = {}\nƙare", call);
+        let error = run_with_limits(&source, Some(100), None).err().expect("Expected an error");
+        assert!(matches!(error, Error::LimitExceeded(..)), "{}: {}", call, error.message());
+    }
+}
+
+#[test]
+fn test_step_budget_restarts_with_each_run() {
+    let mut interpreter = Interpreter::new();
+    interpreter.set_step_limit(Some(15));
+
+    for _ in 0..3 {
+        let program = parse(tokenize("fara\nga i cikin 0..10 {\n}\nƙare").unwrap()).unwrap();
+        interpreter.execute(program).expect("Each run has its own budget");
+        assert_eq!(interpreter.steps(), 10);
+    }
+}
+
+#[test]
+fn test_limits_cannot_be_caught() {
+    let source = "fara
+var an_kama = karya
+gwada {
+    maimaita (gaskiya) {
+    }
+} kama e {
+    an_kama = gaskiya
+}
+ƙare";
+    let error = run_with_limits(source, Some(100), None).err().expect("Expected an error");
+
+    assert!(matches!(error, Error::LimitExceeded(..)));
+    assert!(!error.is_catchable());
+}
+
+#[test]
+fn test_time_limit_stops_endless_loop() {
+    let started = Instant::now();
+    let error = run_with_limits(ENDLESS_LOOP, None, Some(Duration::from_millis(50)))
+        .err().expect("Expected an error");
+
+    assert!(matches!(error, Error::LimitExceeded(..)));
+    assert!(error.message().contains("time limit exceeded"), "{}", error.message());
+    assert!(error.message().contains("50 ms"));
+    assert!(started.elapsed() < Duration::from_secs(10));
+}
+
+#[test]
+fn test_limit_error_carries_traceback() {
+    let error = run_with_limits("fara
+aiki juya() {
+    maimaita (gaskiya) {
+    }
+}
+juya()
+ƙare", Some(100), None).err().expect("Expected an error");
+
+    assert_eq!(error.traceback().len(), 1);
+    assert_eq!(error.traceback()[0].function, "juya");
+}
+
+#[test]
+fn test_no_limits_by_default() {
+    let interpreter = run_with_limits("fara\nga i cikin 0..5000 {\n}\nƙare", None, None).expect("No limit");
+    assert_eq!(interpreter.steps(), 5000);
+}
@@ -0,0 +1,115 @@
+//! Tests pour les boucles ga: jimla, ƙamus et la forme à deux variables
+
+mod common;
+
+use dabara::interpreter::{graphemes, Interpreter, Value};
+use common::run;
+
+/// Retourne les éléments d'une variable de type jeri sous forme de texte
+fn items(interpreter: &Interpreter, name: &str) -> Vec<String> {
+    match interpreter.get_variable(name) {
+        Some(Value::List(elements)) => elements.borrow().iter().map(|v| v.to_string()).collect(),
+        other => panic!("Expected list for '{}', got {:?}", name, other),
+    }
+}
+
+#[test]
+fn test_for_loop_over_string_letters() {
+    let source = r#"
+fara
+  var haruffa = []
+  ga harafi cikin "ƙasa" {
+    haruffa.kara(harafi)
+  }
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(items(&interpreter, "haruffa"), vec!["ƙ", "a", "s", "a"]);
+}
+
+#[test]
+fn test_for_loop_keeps_combining_marks_with_their_letter() {
+    // "kàɗa" avec le ton écrit comme accent combinant (a + U+0300)
+    let source = "fara\n  var haruffa = []\n  ga h cikin \"ka\u{0300}ɗa\" {\n    haruffa.kara(h)\n  }\nƙare";
+    let interpreter = run(source).unwrap();
+    assert_eq!(items(&interpreter, "haruffa"), vec!["k", "a\u{0300}", "ɗ", "a"]);
+}
+
+#[test]
+fn test_string_operations_count_the_same_letters_as_the_loop() {
+    // "kàɗa" : 5 caractères Unicode, 4 lettres visibles
+    let source = "fara\n  var s = \"ka\u{0300}ɗa\"\n  var tsawo = s.tsawo()\n  var na_biyu = s[1]\n  var na_karshe = s[-1]\n  var yanki = s.yanki(1, 3)\n  var haruffa = list(s)\nƙare";
+    let interpreter = run(source).unwrap();
+
+    assert!(matches!(interpreter.get_variable("tsawo"), Some(Value::Number(4))));
+    assert_eq!(interpreter.get_variable("na_biyu").map(|v| v.to_string()).as_deref(), Some("a\u{0300}"));
+    assert_eq!(interpreter.get_variable("na_karshe").map(|v| v.to_string()).as_deref(), Some("a"));
+    assert_eq!(interpreter.get_variable("yanki").map(|v| v.to_string()).as_deref(), Some("a\u{0300}ɗ"));
+    assert_eq!(items(&interpreter, "haruffa"), vec!["k", "a\u{0300}", "ɗ", "a"]);
+}
+
+#[test]
+fn test_graphemes_helper() {
+    assert_eq!(graphemes("ƙaƴa"), vec!["ƙ", "a", "ƴ", "a"]);
+    assert_eq!(graphemes(""), Vec::<String>::new());
+    // Séquence emoji liée par ZWJ
+    assert_eq!(graphemes("a👩\u{200D}💻b"), vec!["a", "👩\u{200D}💻", "b"]);
+}
+
+#[test]
+fn test_for_loop_with_index_and_element() {
+    let source = r#"
+fara
+  var sunaye = ["Ahmad", "Fatima", "Musa"]
+  var layi = []
+  ga i, suna cikin sunaye {
+    layi.kara(i + ":" + suna)
+  }
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(items(&interpreter, "layi"), vec!["0:Ahmad", "1:Fatima", "2:Musa"]);
+}
+
+#[test]
+fn test_for_loop_with_index_over_map_and_string() {
+    let source = r#"
+fara
+  var m = {"a": 1, "b": 2}
+  var maballai = []
+  ga i, k cikin m {
+    maballai.kara(i + k)
+  }
+  var haruffa = []
+  ga i, h cikin "ɗan" {
+    haruffa.kara(h + i)
+  }
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(items(&interpreter, "maballai"), vec!["0a", "1b"]);
+    assert_eq!(items(&interpreter, "haruffa"), vec!["ɗ0", "a1", "n2"]);
+}
+
+#[test]
+fn test_for_loop_with_index_over_range_and_break() {
+    let source = r#"
+fara
+  var jimilla = 0
+  ga i, n cikin 10..100 taki 10 {
+    idan i == 3 {
+      katse
+    }
+    jimilla += n
+  }
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert!(matches!(interpreter.get_variable("jimilla"), Some(Value::Number(60))));
+}
+
+#[test]
+fn test_for_loop_over_number_is_error() {
+    let error = run("fara\n  ga i cikin 5 {\n    rubuta i\n  }\nƙare").err().expect("Expected an error");
+    assert!(error.to_string().contains("For loop requires a list, a range, a string or a map"), "{}", error);
+}
@@ -0,0 +1,271 @@
+//! Tests pour les opérateurs logiques et arithmétiques
+
+mod common;
+
+use dabara::{lexer::tokenize, interpreter::Value, Token};
+use common::{run, boolean};
+
+#[test]
+fn test_tokenizer_logical_operators() {
+    let tokens: Vec<Token> = tokenize("da ko ba && || !")
+        .unwrap()
+        .into_iter()
+        .map(|t| t.token)
+        .collect();
+
+    assert_eq!(&tokens[..6], &[Token::And, Token::Or, Token::Not, Token::And, Token::Or, Token::Not]);
+}
+
+#[test]
+fn test_logical_keywords_and_symbols() {
+    let source = r#"
+fara
+  var shekaru = 20
+  var kudi = 0
+  var a = shekaru > 18 da kudi > 0
+  var b = shekaru > 18 ko kudi > 0
+  var c = ba shekaru > 18
+  var d = shekaru > 18 && kudi == 0
+  var e = !(kudi > 0) || karya
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    assert!(!boolean(&interpreter, "a"));
+    assert!(boolean(&interpreter, "b"));
+    assert!(!boolean(&interpreter, "c"));
+    assert!(boolean(&interpreter, "d"));
+    assert!(boolean(&interpreter, "e"));
+}
+
+#[test]
+fn test_logical_precedence() {
+    // `da` lie plus fort que `ko`, et `ba` s'applique à toute la comparaison
+    let source = r#"
+fara
+  var a = gaskiya ko gaskiya da karya
+  var b = ba 1 == 2
+  var c = ba gaskiya ko gaskiya
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    assert!(boolean(&interpreter, "a"));
+    assert!(boolean(&interpreter, "b"));
+    assert!(boolean(&interpreter, "c"));
+}
+
+#[test]
+fn test_logical_short_circuit() {
+    // La partie droite n'est pas évaluée: la variable inconnue ne provoque pas d'erreur
+    let source = r#"
+fara
+  var jeri = []
+  var a = karya da babu_shi
+  var b = gaskiya ko babu_shi
+  var c = jeri.tsawo() > 0 da jeri[0] == 1
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    assert!(!boolean(&interpreter, "a"));
+    assert!(boolean(&interpreter, "b"));
+    assert!(!boolean(&interpreter, "c"));
+}
+
+#[test]
+fn test_logical_operators_in_conditions() {
+    let source = r#"
+fara
+  var sakamako = 0
+  var i = 0
+  maimaita (i < 10 da sakamako < 3) {
+    idan (i == 2 ko i == 5) {
+      sakamako += 1
+    }
+    i += 1
+  }
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    match interpreter.get_variable("sakamako") {
+        Some(Value::Number(n)) => assert_eq!(*n, 2),
+        other => panic!("Expected number, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_reserved_word_as_name_is_named_in_error() {
+    let cases = [
+        ("fara\n  var da = 1\nƙare", "'da'", (2, 7)),
+        ("fara\n  ko = 2\nƙare", "'ko'", (2, 3)),
+        ("fara\n  aiki f(ba) {\n    mayar 1\n  }\nƙare", "'ba'", (2, 10)),
+        ("fara\n  ga taki cikin 0..3 {\n  }\nƙare", "'taki'", (2, 6)),
+        ("fara\n  aiki gwaji() {\n  }\nƙare", "'gwaji'", (2, 8)),
+    ];
+
+    for (source, word, position) in cases {
+        let error = run(source).err().expect("Expected an error");
+        assert!(matches!(error, dabara::Error::ParseError(..)), "{}", source);
+        assert!(error.message().contains(word), "{}: {}", source, error.message());
+        assert!(error.message().contains("reserved word"), "{}", source);
+        assert_eq!(error.span().map(|s| (s.line, s.column)), Some(position), "{}", source);
+    }
+}
+
+#[test]
+fn test_tokenizer_arithmetic_operators() {
+    let tokens: Vec<Token> = tokenize("7 % 2 // 3 ** 4 * 5 / 6")
+        .unwrap()
+        .into_iter()
+        .map(|t| t.token)
+        .collect();
+
+    assert_eq!(tokens[1], Token::Modulo);
+    assert_eq!(tokens[3], Token::FloorDivide);
+    assert_eq!(tokens[5], Token::Power);
+    assert_eq!(tokens[7], Token::Multiply);
+    assert_eq!(tokens[9], Token::Divide);
+}
+
+#[test]
+fn test_modulo_and_floor_division() {
+    let source = r#"
+fara
+  var a = 7 % 3
+  var b = -7 % 3
+  var c = 7 // 2
+  var d = -7 // 2
+  var e = 7.5 % 2
+  var f = 7.5 // 2
+  var mai_ma = 10 % 2 == 0
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    let show = |name: &str| interpreter.get_variable(name).unwrap().to_string();
+    assert_eq!(show("a"), "1");
+    assert_eq!(show("b"), "2");
+    assert_eq!(show("c"), "3");
+    assert_eq!(show("d"), "-4");
+    assert_eq!(show("e"), "1.5");
+    assert_eq!(show("f"), "3");
+    assert!(boolean(&interpreter, "mai_ma"));
+}
+
+#[test]
+fn test_power_operator() {
+    let source = r#"
+fara
+  var a = 2 ** 10
+  var b = 2 ** 3 ** 2
+  var c = -2 ** 2
+  var d = 2 ** -1
+  var e = 2 * 3 ** 2
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    match interpreter.get_variable("a") {
+        Some(Value::Number(n)) => assert_eq!(*n, 1024),
+        other => panic!("Expected number, got {:?}", other),
+    }
+    let show = |name: &str| interpreter.get_variable(name).unwrap().to_string();
+    assert_eq!(show("b"), "512");
+    assert_eq!(show("c"), "-4");
+    assert_eq!(show("d"), "0.5");
+    assert_eq!(show("e"), "18");
+}
+
+#[test]
+fn test_modulo_by_zero() {
+    for source in ["fara\n  var a = 5 % 0\nƙare", "fara\n  var a = 5 // 0\nƙare", "fara\n  var a = 5.0 % 0\nƙare"] {
+        let error = run(source).err().expect("Expected an error");
+        assert!(error.message().starts_with("Ba za a iya raba da sifili"));
+        assert!(error.hint().is_some());
+    }
+}
+
+#[test]
+fn test_factorial_overflow_is_a_runtime_error() {
+    let source = r#"
+fara
+  aiki factorial(n) {
+    idan (n <= 1) {
+      mayar 1
+    }
+    mayar n * factorial(n - 1)
+  }
+  var a = factorial(20)
+  var b = factorial(25)
+ƙare
+"#;
+
+    let error = run(source).err().expect("Expected an overflow error");
+
+    assert!(matches!(error, dabara::Error::RuntimeError(..)));
+    assert!(error.message().starts_with("Lambar ta yi girma da yawa"));
+    assert!(error.hint().is_some());
+    assert_eq!(error.span().map(|s| s.line), Some(7));
+}
+
+#[test]
+fn test_factorial_with_floats_does_not_overflow() {
+    let source = r#"
+fara
+  aiki factorial(n) {
+    idan (n <= 1) {
+      mayar 1.0
+    }
+    mayar n * factorial(n - 1)
+  }
+  var a = factorial(25)
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    match interpreter.get_variable("a") {
+        Some(Value::Float(f)) => assert!((f - 1.5511210043330986e25).abs() < 1e10),
+        other => panic!("Expected float, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_checked_integer_operations() {
+    let overflowing = [
+        "var a = 9223372036854775807 + 1",
+        "var a = -9223372036854775807 - 2",
+        "var a = 4611686018427387904 * 2",
+        "var a = 2 ** 63",
+        "var a = sum([9223372036854775807, 1])",
+        "var a = (-9223372036854775807 - 1) // -1",
+        "var a = -(-9223372036854775807 - 1)",
+    ];
+
+    for statement in overflowing {
+        let source = format!("fara\n  {}\nƙare", statement);
+        let error = run(&source).err().unwrap_or_else(|| panic!("Expected overflow for `{}`", statement));
+        assert!(error.message().contains("integer overflow"), "{}: {}", statement, error);
+    }
+
+    let interpreter = run("fara\n  var a = 2 ** 62\n  var b = (-9223372036854775807 - 1) % -1\nƙare")
+        .expect("Failed to execute");
+    assert_eq!(interpreter.get_variable("a").unwrap().to_string(), "4611686018427387904");
+    assert_eq!(interpreter.get_variable("b").unwrap().to_string(), "0");
+}
+
+#[test]
+fn test_integer_literal_too_large() {
+    let error = run("fara\n  var a = 99999999999999999999\nƙare").err().expect("Expected an error");
+
+    assert!(matches!(error, dabara::Error::LexError(..)));
+    assert_eq!(error.span().map(|s| (s.line, s.column)), Some((2, 11)));
+}
@@ -0,0 +1,186 @@
+//! Tests pour les intervalles (zango) : `1..10`, `1..=10`, `taki`
+
+mod common;
+
+use dabara::{lexer::tokenize, interpreter::{Interpreter, Value}, Token};
+use common::{run, number, boolean};
+
+/// Retourne les entiers d'une variable de type jeri
+fn numbers(interpreter: &Interpreter, name: &str) -> Vec<i64> {
+    match interpreter.get_variable(name) {
+        Some(Value::List(elements)) => elements.borrow().iter().map(|v| match v {
+            Value::Number(n) => *n,
+            other => panic!("Expected number in '{}', got {:?}", name, other),
+        }).collect(),
+        other => panic!("Expected list for '{}', got {:?}", name, other),
+    }
+}
+
+/// Retourne la chaîne d'une variable globale
+fn text<'a>(interpreter: &'a Interpreter, name: &str) -> &'a str {
+    match interpreter.get_variable(name) {
+        Some(Value::String(s)) => s,
+        other => panic!("Expected string for '{}', got {:?}", name, other),
+    }
+}
+
+#[test]
+fn test_tokenizer_range_operators() {
+    let tokens: Vec<Token> = tokenize("1..10 1..=10 taki")
+        .unwrap()
+        .into_iter()
+        .map(|t| t.token)
+        .collect();
+
+    assert_eq!(&tokens[..7], &[
+        Token::Number(1), Token::DotDot, Token::Number(10),
+        Token::Number(1), Token::DotDotEqual, Token::Number(10),
+        Token::Step,
+    ]);
+}
+
+#[test]
+fn test_large_range_loop_is_lazy() {
+    let source = r#"
+fara
+  var jimilla = 0
+  ga i cikin 1..1000000 {
+    jimilla += i
+  }
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(number(&interpreter, "jimilla"), 499999500000);
+}
+
+#[test]
+fn test_exclusive_and_inclusive_ranges() {
+    let source = r#"
+fara
+  var a = list(1..5)
+  var b = list(1..=5)
+  var c = list(5..1)
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(numbers(&interpreter, "a"), vec![1, 2, 3, 4]);
+    assert_eq!(numbers(&interpreter, "b"), vec![1, 2, 3, 4, 5]);
+    assert!(numbers(&interpreter, "c").is_empty());
+}
+
+#[test]
+fn test_range_with_step() {
+    let source = r#"
+fara
+  var a = list(0..10 taki 3)
+  var b = list(10..0 taki -3)
+  var c = list(10..=0 taki -5)
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(numbers(&interpreter, "a"), vec![0, 3, 6, 9]);
+    assert_eq!(numbers(&interpreter, "b"), vec![10, 7, 4, 1]);
+    assert_eq!(numbers(&interpreter, "c"), vec![10, 5, 0]);
+}
+
+#[test]
+fn test_range_bounds_are_expressions() {
+    let source = r#"
+fara
+  var n = 3
+  var a = list(n - 1..n * 2)
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(numbers(&interpreter, "a"), vec![2, 3, 4, 5]);
+}
+
+#[test]
+fn test_range_length_and_indexing() {
+    let source = r#"
+fara
+  var z = 0..100 taki 10
+  var tsawo = z.tsawo()
+  var farko = z[0]
+  var na_uku = z[2]
+  var karshe = z[-1]
+  var babba = (1..=9223372036854775807).tsawo()
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(number(&interpreter, "tsawo"), 10);
+    assert_eq!(number(&interpreter, "farko"), 0);
+    assert_eq!(number(&interpreter, "na_uku"), 20);
+    assert_eq!(number(&interpreter, "karshe"), 90);
+    assert_eq!(number(&interpreter, "babba"), i64::MAX);
+}
+
+#[test]
+fn test_range_index_out_of_bounds() {
+    let err = run("fara\n  var z = 1..4\n  rubuta z[3]\nƙare").err().expect("Expected an error");
+    assert!(err.to_string().contains("Index out of bounds"), "{}", err);
+}
+
+#[test]
+fn test_range_membership() {
+    let source = r#"
+fara
+  var z = 0..10 taki 2
+  var a = z.yana_da(4)
+  var b = z.yana_da(5)
+  var c = z.yana_da(10)
+  var d = (10..=0 taki -5).yana_da(0)
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert!(boolean(&interpreter, "a"));
+    assert!(!boolean(&interpreter, "b"));
+    assert!(!boolean(&interpreter, "c"));
+    assert!(boolean(&interpreter, "d"));
+}
+
+#[test]
+fn test_range_display() {
+    let source = r#"
+fara
+  var a = string(1..10)
+  var b = string(1..=10)
+  var c = string(0..10 taki 2)
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(text(&interpreter, "a"), "1..10");
+    assert_eq!(text(&interpreter, "b"), "1..=10");
+    assert_eq!(text(&interpreter, "c"), "0..10 taki 2");
+}
+
+#[test]
+fn test_range_step_zero_is_error() {
+    let err = run("fara\n  var z = 1..10 taki 0\nƙare").err().expect("Expected an error");
+    assert!(err.to_string().contains("range step cannot be zero"), "{}", err);
+}
+
+#[test]
+fn test_range_requires_integers() {
+    let err = run("fara\n  var z = 1..\"goma\"\nƙare").err().expect("Expected an error");
+    assert!(err.to_string().contains("range bounds must be integers"), "{}", err);
+}
+
+#[test]
+fn test_range_equality() {
+    let source = r#"
+fara
+  var daidai = (1..5) == (1..5)
+  var daidai_taki = (0..10 taki 3) == (0..=9 taki 3)
+  var banbanci = (1..5) != (1..=5)
+  var ba_jeri = (1..3) == [1, 2]
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    assert!(boolean(&interpreter, "daidai"));
+    assert!(boolean(&interpreter, "daidai_taki"));
+    assert!(boolean(&interpreter, "banbanci"));
+    assert!(!boolean(&interpreter, "ba_jeri"));
+}
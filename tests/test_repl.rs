@@ -0,0 +1,68 @@
+//! Tests pour la session interactive (REPL)
+
+use dabara::error::RenderStyle;
+use dabara::interpreter::Value;
+use dabara::repl::{Repl, ReplStep, CONTINUATION_PROMPT, PROMPT};
+
+#[test]
+fn test_state_persists_between_lines() {
+    let mut repl = Repl::new(RenderStyle::Plain);
+
+    assert_eq!(repl.feed_line("var x = 20"), ReplStep::Done(None));
+    assert_eq!(repl.feed_line("x + 1"), ReplStep::Done(Some("21".to_string())));
+
+    match repl.interpreter().get_variable("x") {
+        Some(Value::Number(n)) => assert_eq!(*n, 20),
+        other => panic!("Expected number, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_multiline_block() {
+    let mut repl = Repl::new(RenderStyle::Plain);
+
+    assert_eq!(repl.feed_line("aiki ninka(a, b) {"), ReplStep::Incomplete);
+    assert_eq!(repl.prompt(), CONTINUATION_PROMPT);
+    assert_eq!(repl.feed_line("  mayar a * b"), ReplStep::Incomplete);
+    assert_eq!(repl.feed_line("}"), ReplStep::Done(None));
+    assert_eq!(repl.prompt(), PROMPT);
+
+    assert_eq!(repl.feed_line("ninka(6, 7)"), ReplStep::Done(Some("42".to_string())));
+    assert_eq!(repl.history().len(), 2);
+}
+
+#[test]
+fn test_error_keeps_session_alive() {
+    let mut repl = Repl::new(RenderStyle::Plain);
+
+    repl.feed_line("var x = 1");
+    match repl.feed_line("x / 0") {
+        ReplStep::Failed(diagnostic) => assert!(diagnostic.contains("<repl>:1:")),
+        other => panic!("Expected failure, got {:?}", other),
+    }
+    assert_eq!(repl.feed_line("x"), ReplStep::Done(Some("1".to_string())));
+}
+
+#[test]
+fn test_meta_commands() {
+    let mut repl = Repl::new(RenderStyle::Plain);
+
+    repl.feed_line("var suna = \"Musa\"");
+    repl.feed_line("aiki gaisuwa(mutum) { rubuta \"Sannu \" + mutum }");
+
+    match repl.feed_line(":vars") {
+        ReplStep::Done(Some(output)) => assert!(output.contains("suna = Musa")),
+        other => panic!("Expected output, got {:?}", other),
+    }
+    match repl.feed_line(":functions") {
+        ReplStep::Done(Some(output)) => assert_eq!(output, "aiki gaisuwa(mutum)"),
+        other => panic!("Expected output, got {:?}", other),
+    }
+
+    repl.feed_line(":reset");
+    assert!(repl.interpreter().get_variable("suna").is_none());
+    assert!(repl.interpreter().functions.is_empty());
+
+    assert!(matches!(repl.feed_line(":babu"), ReplStep::Failed(_)));
+    assert_eq!(repl.feed_line(":quit"), ReplStep::Quit);
+}
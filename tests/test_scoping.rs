@@ -0,0 +1,258 @@
+//! Tests qui fixent la portée lexicale: fonctions, blocs et fonctions imbriquées
+
+mod common;
+
+use common::{run, shown};
+
+#[test]
+fn test_function_does_not_see_caller_locals() {
+    let source = r#"
+fara
+  aiki nuna() {
+    mayar boye
+  }
+  aiki kira() {
+    var boye = 5
+    mayar nuna()
+  }
+  var a = kira()
+ƙare
+"#;
+    let error = run(source).err().expect("Expected an error");
+    assert!(error.to_string().contains("boye"), "{}", error);
+}
+
+#[test]
+fn test_free_variables_resolve_where_function_is_defined() {
+    let source = r#"
+fara
+  aiki mai_gaisuwa() {
+    var kalma = "Sannu"
+    mayar aiki(suna) { mayar kalma + " " + suna }
+  }
+  aiki kira(f) {
+    var kalma = "Bankwana"
+    mayar f("Musa")
+  }
+  var a = kira(mai_gaisuwa())
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(shown(&interpreter, "a"), "Sannu Musa");
+}
+
+#[test]
+fn test_global_functions_see_current_globals() {
+    let source = r#"
+fara
+  var kudi = 1
+  aiki karanta() {
+    mayar kudi
+  }
+  kudi = 7
+  var a = karanta()
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(shown(&interpreter, "a"), "7");
+}
+
+#[test]
+fn test_block_local_var_does_not_leak() {
+    let source = r#"
+fara
+  idan gaskiya {
+    var sabo = 1
+  }
+  var n = 0
+  maimaita (n < 2) {
+    var cikin_madauki = n
+    n += 1
+  }
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert!(interpreter.get_variable("sabo").is_none());
+    assert!(interpreter.get_variable("cikin_madauki").is_none());
+
+    let error = run("fara\n  idan gaskiya {\n    var sabo = 1\n  }\n  rubuta sabo\nƙare")
+        .err().expect("Expected an error");
+    assert!(error.to_string().contains("sabo"), "{}", error);
+}
+
+#[test]
+fn test_var_in_block_updates_existing_variable() {
+    let source = r#"
+fara
+  naɗa lambar = 1
+  maimaita (lambar <= 5) {
+    naɗa lambar = lambar + 1
+  }
+  aiki kirga() {
+    var jimilla = 0
+    ga i cikin 1..=4 {
+      var jimilla = jimilla + i
+    }
+    mayar jimilla
+  }
+  var a = kirga()
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(shown(&interpreter, "lambar"), "6");
+    assert_eq!(shown(&interpreter, "a"), "10");
+}
+
+#[test]
+fn test_var_in_function_shadows_global() {
+    let source = r#"
+fara
+  var x = 10
+  aiki canja() {
+    var x = 20
+    idan gaskiya {
+      var x = 30
+    }
+    mayar x
+  }
+  var a = canja()
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(shown(&interpreter, "x"), "10");
+    assert_eq!(shown(&interpreter, "a"), "30");
+}
+
+#[test]
+fn test_var_in_block_updates_parameter_instead_of_shadowing() {
+    let source = r#"
+fara
+  aiki ninka(n) {
+    idan n > 0 {
+      var n = n * 2
+    }
+    mayar n
+  }
+  var a = ninka(21)
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(shown(&interpreter, "a"), "42");
+}
+
+#[test]
+fn test_var_in_nested_function_shadows_enclosing_function() {
+    let source = r#"
+fara
+  aiki waje() {
+    var x = 1
+    aiki ciki() {
+      var x = 2
+      mayar x
+    }
+    var b = ciki()
+    mayar x * 10 + b
+  }
+  var a = waje()
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(shown(&interpreter, "a"), "12");
+}
+
+#[test]
+fn test_loop_variables_stay_in_the_loop() {
+    let source = r#"
+fara
+  var i = "waje"
+  ga i cikin [1, 2, 3] {
+    rubuta i
+  }
+  ga j, abu cikin ["a"] {
+    rubuta abu
+  }
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(shown(&interpreter, "i"), "waje");
+    assert!(interpreter.get_variable("j").is_none());
+    assert!(interpreter.get_variable("abu").is_none());
+}
+
+#[test]
+fn test_closures_capture_each_loop_iteration() {
+    let source = r#"
+fara
+  var ayyuka = []
+  ga i cikin 0..3 {
+    ayyuka.kara(aiki() { mayar i })
+  }
+  var a = map(ayyuka, aiki(f) { mayar f() })
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(shown(&interpreter, "a"), "[0, 1, 2]");
+}
+
+#[test]
+fn test_nested_function_definitions() {
+    let source = r#"
+fara
+  aiki waje(n) {
+    aiki murabba(x) {
+      mayar x * x
+    }
+    aiki factorial(k) {
+      idan k <= 1 {
+        mayar 1
+      }
+      mayar k * factorial(k - 1)
+    }
+    mayar murabba(n) + factorial(n)
+  }
+  var a = waje(4)
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(shown(&interpreter, "a"), "40");
+    assert!(interpreter.functions.contains_key("waje"));
+    assert!(!interpreter.functions.contains_key("murabba"));
+
+    let error = run("fara\n  aiki waje() {\n    aiki ciki() {\n      mayar 1\n    }\n    mayar 0\n  }\n  waje()\n  ciki()\nƙare")
+        .err().expect("Expected an error");
+    assert!(error.to_string().contains("ciki"), "{}", error);
+}
+
+#[test]
+fn test_nested_function_sees_enclosing_parameters() {
+    let source = r#"
+fara
+  aiki ninka_duka(jeri, sau) {
+    aiki ninka(x) {
+      mayar x * sau
+    }
+    mayar map(jeri, ninka)
+  }
+  var a = ninka_duka([1, 2, 3], 10)
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(shown(&interpreter, "a"), "[10, 20, 30]");
+}
+
+#[test]
+fn test_assignment_reaches_enclosing_scopes() {
+    let source = r#"
+fara
+  var jimilla = 0
+  aiki kara(n) {
+    jimilla += n
+  }
+  ga i cikin 1..=3 {
+    kara(i)
+  }
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(shown(&interpreter, "jimilla"), "6");
+}
@@ -0,0 +1,127 @@
+//! Tests pour les fonctions natives de la bibliothèque standard
+
+mod common;
+
+use dabara::interpreter::{Interpreter, Value};
+use common::run;
+
+#[test]
+fn test_math_functions() {
+    let source = r#"
+fara
+  var a = sqrt(16)
+  var b = abs(-5)
+  var c = pow(2, 10)
+  var d = floor(3.7)
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    match interpreter.get_variable("a") {
+        Some(Value::Float(f)) => assert_eq!(*f, 4.0),
+        other => panic!("Expected float, got {:?}", other),
+    }
+    match interpreter.get_variable("b") {
+        Some(Value::Number(n)) => assert_eq!(*n, 5),
+        other => panic!("Expected number, got {:?}", other),
+    }
+    match interpreter.get_variable("c") {
+        Some(Value::Float(f)) => assert_eq!(*f, 1024.0),
+        other => panic!("Expected float, got {:?}", other),
+    }
+    match interpreter.get_variable("d") {
+        Some(Value::Number(n)) => assert_eq!(*n, 3),
+        other => panic!("Expected number, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_variadic_functions() {
+    let source = r#"
+fara
+  var karami = min(7, 3, 9, 4)
+  var babba = max(7, 3, 9, 4)
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    match interpreter.get_variable("karami") {
+        Some(Value::Number(n)) => assert_eq!(*n, 3),
+        other => panic!("Expected number, got {:?}", other),
+    }
+    match interpreter.get_variable("babba") {
+        Some(Value::Number(n)) => assert_eq!(*n, 9),
+        other => panic!("Expected number, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_math_constants() {
+    let interpreter = Interpreter::new();
+
+    match interpreter.get_variable("DABARAN_PI") {
+        Some(Value::Float(f)) => assert_eq!(*f, std::f64::consts::PI),
+        other => panic!("Expected float, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_conversion_and_list_functions() {
+    let source = r#"
+fara
+  var lamba = int("42")
+  var jimla = sum([1, 2, 3, 4])
+  var jeri = sort([3, 1, 2])
+  var akwai = contains("Sannu duniya", "duniya")
+ƙare
+"#;
+
+    let interpreter = run(source).expect("Failed to execute");
+
+    match interpreter.get_variable("lamba") {
+        Some(Value::Number(n)) => assert_eq!(*n, 42),
+        other => panic!("Expected number, got {:?}", other),
+    }
+    match interpreter.get_variable("jimla") {
+        Some(Value::Number(n)) => assert_eq!(*n, 10),
+        other => panic!("Expected number, got {:?}", other),
+    }
+    match interpreter.get_variable("jeri") {
+        Some(value) => assert_eq!(value.to_string(), "[1, 2, 3]"),
+        None => panic!("Variable not found"),
+    }
+    match interpreter.get_variable("akwai") {
+        Some(Value::Boolean(b)) => assert!(*b),
+        other => panic!("Expected boolean, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_native_arity_error() {
+    let source = r#"
+fara
+  var a = sqrt(1, 2)
+ƙare
+"#;
+
+    assert!(run(source).is_err());
+
+    let source = r#"
+fara
+  var a = min()
+ƙare
+"#;
+
+    assert!(run(source).is_err());
+}
+
+#[test]
+fn test_stdlib_survives_reset() {
+    let mut interpreter = Interpreter::new();
+    interpreter.clear_variables();
+
+    assert!(interpreter.native_functions.contains_key("sqrt"));
+    assert!(interpreter.get_variable("DABARAN_PI").is_some());
+}
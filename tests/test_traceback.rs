@@ -0,0 +1,175 @@
+//! Tests pour la pile des appels (traceback) et la limite de récursion
+
+mod common;
+
+use dabara::{lexer::tokenize, parser::parse, interpreter::Interpreter, Error, Frame};
+use dabara::interpreter::DEFAULT_MAX_CALL_DEPTH;
+use dabara::error::RenderStyle;
+use common::{run_error, with_large_stack};
+
+/// Nom et position (ligne, colonne) de chaque appel de la pile
+fn frames(error: &Error) -> Vec<(String, Option<(usize, usize)>)> {
+    error.traceback().iter()
+        .map(|Frame { function, call_site }| (function.clone(), call_site.map(|s| (s.line, s.column))))
+        .collect()
+}
+
+const NESTED_CALLS: &str = "fara
+aiki raba(a, b) {
+    mayar a / b
+}
+aiki lissafi(x) {
+    mayar raba(x, 0)
+}
+rubuta lissafi(5)
+ƙare";
+
+#[test]
+fn test_traceback_lists_calls_most_recent_first() {
+    let error = run_error(NESTED_CALLS);
+
+    assert!(matches!(error, Error::RuntimeError(..)));
+    assert_eq!(error.span().map(|s| (s.line, s.column)), Some((3, 13)));
+    assert_eq!(frames(&error), vec![
+        ("raba".to_string(), Some((6, 11))),
+        ("lissafi".to_string(), Some((8, 8))),
+    ]);
+}
+
+#[test]
+fn test_top_level_error_has_no_traceback() {
+    let error = run_error("fara\nrubuta 1 / 0\nƙare");
+
+    assert!(error.traceback().is_empty());
+    assert!(!error.render("fara\nrubuta 1 / 0\nƙare", "a.ha", RenderStyle::Plain).contains("tarihin kira"));
+}
+
+#[test]
+fn test_render_includes_hausa_traceback() {
+    let error = run_error(NESTED_CALLS);
+    let rendered = error.render(NESTED_CALLS, "lissafi.ha", RenderStyle::Plain);
+
+    assert!(rendered.contains("tarihin kira (traceback)"), "{}", rendered);
+    assert!(rendered.contains("a cikin 'raba', an kira a lissafi.ha:6:11"), "{}", rendered);
+    assert!(rendered.contains("a cikin 'lissafi', an kira a lissafi.ha:8:8"), "{}", rendered);
+
+    // La pile vient après la ligne soulignée et avant le conseil
+    let traceback = rendered.find("tarihin kira").unwrap();
+    assert!(rendered.find('^').unwrap() < traceback);
+    assert!(traceback < rendered.find("shawara").unwrap());
+}
+
+#[test]
+fn test_traceback_through_higher_order_function() {
+    let error = run_error("fara
+aiki ninka_duka(jeri) {
+    mayar map(jeri, aiki(x) { mayar x / 0 })
+}
+var j = ninka_duka([1, 2])
+ƙare");
+
+    assert_eq!(frames(&error), vec![
+        ("aiki".to_string(), None),
+        ("map".to_string(), Some((3, 11))),
+        ("ninka_duka".to_string(), Some((5, 9))),
+    ]);
+}
+
+#[test]
+fn test_traceback_for_value_calls() {
+    let error = run_error("fara
+var f = aiki(x) { mayar x + \"a\" * karya }
+f(1)
+ƙare");
+
+    assert_eq!(frames(&error), vec![("aiki".to_string(), Some((3, 1)))]);
+}
+
+#[test]
+fn test_thrown_error_carries_traceback() {
+    let error = run_error("fara
+aiki duba(n) {
+    idan n < 0 {
+        jefa \"lamba mara kyau\"
+    }
+    mayar n
+}
+duba(-1)
+ƙare");
+
+    assert!(matches!(error, Error::Thrown(..)));
+    assert_eq!(frames(&error), vec![("duba".to_string(), Some((8, 1)))]);
+}
+
+#[test]
+fn test_call_stack_unwinds_after_caught_error() {
+    let error = run_error("fara
+aiki raba(a, b) {
+    mayar a / b
+}
+gwada {
+    raba(1, 0)
+} kama e {
+    rubuta e.saƙo()
+}
+var x = y
+ƙare");
+
+    assert!(error.message().contains("'y'"));
+    assert!(error.traceback().is_empty());
+}
+
+#[test]
+fn test_recursion_depth_limit() {
+    let error = with_large_stack(|| run_error("fara
+aiki har_abada(n) {
+    mayar har_abada(n + 1)
+}
+har_abada(0)
+ƙare"));
+
+    assert!(matches!(error, Error::RuntimeError(..)));
+    assert!(error.message().contains("maximum call depth exceeded"), "{}", error.message());
+    assert_eq!(error.traceback().len(), DEFAULT_MAX_CALL_DEPTH);
+}
+
+#[test]
+fn test_recursion_below_limit_succeeds() {
+    let result = std::thread::Builder::new()
+        .stack_size(256 * 1024 * 1024)
+        .spawn(|| {
+            let source = "fara
+aiki jimla(n) {
+    idan n == 0 {
+        mayar 0
+    }
+    mayar n + jimla(n - 1)
+}
+var sakamako = jimla(900)
+ƙare";
+            let mut interpreter = Interpreter::new();
+            interpreter.execute(parse(tokenize(source)?)?)?;
+            Ok::<_, Error>(interpreter.get_variable("sakamako").map(|v| v.to_string()))
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+
+    assert_eq!(result.ok().flatten().as_deref(), Some("405450"));
+}
+
+#[test]
+fn test_render_collapses_repeated_frames() {
+    let source = "fara
+aiki har_abada(n) {
+    mayar har_abada(n + 1)
+}
+har_abada(0)
+ƙare";
+    let error = with_large_stack(move || run_error(source));
+    let rendered = error.render(source, "a.ha", RenderStyle::Plain);
+
+    assert_eq!(rendered.matches("an kira a a.ha:3:11").count(), 3, "{}", rendered);
+    assert!(rendered.contains(&format!("an maimaita sau {}", DEFAULT_MAX_CALL_DEPTH - 4)), "{}", rendered);
+    assert!(rendered.contains("an kira a a.ha:5:1"), "{}", rendered);
+}
@@ -0,0 +1,205 @@
+//! Tests pour gwada / kama / a_ƙarshe et jefa
+
+mod common;
+
+use dabara::Error;
+use common::{run, shown};
+
+#[test]
+fn test_catch_runtime_error() {
+    let source = r#"
+fara
+  var sakamako = "babu"
+  gwada {
+    var n = int("abc")
+    sakamako = "ba a kai nan ba"
+  } kama kuskure {
+    sakamako = kuskure.iri() + ": " + kuskure.saƙo()
+  }
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(shown(&interpreter, "sakamako"), "Runtime: ba za a iya canza jimla zuwa lambar (cannot convert string to integer)");
+}
+
+#[test]
+fn test_error_value_carries_location() {
+    let source = r#"
+fara
+  var layi = 0
+  var ginshiki = 0
+  gwada {
+    rubuta 1 / 0
+  } kama k {
+    layi = k.layi()
+    ginshiki = k.ginshiki()
+  }
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(shown(&interpreter, "layi"), "6");
+    assert_eq!(shown(&interpreter, "ginshiki"), "14");
+}
+
+#[test]
+fn test_existing_runtime_errors_are_catchable() {
+    let failing = [
+        "var a = babu_irin_wannan",
+        "var j = [1, 2]\n    var a = j[5]",
+        "var a = 9223372036854775807 + 1",
+        "var a = \"a\" - 1",
+        "babu_aiki()",
+        "var a = 1..10 taki 0",
+        "var m = {}\n    m[[1]] = 2",
+    ];
+
+    for statement in failing {
+        let source = format!("fara\n  var kama_shi = karya\n  gwada {{\n    {}\n  }} kama {{\n    kama_shi = gaskiya\n  }}\nƙare", statement);
+        let interpreter = run(&source).unwrap_or_else(|e| panic!("`{}` escaped the catch: {}", statement, e));
+        assert_eq!(shown(&interpreter, "kama_shi"), "gaskiya", "{}", statement);
+    }
+}
+
+#[test]
+fn test_throw_and_catch_user_error() {
+    let source = r#"
+fara
+  aiki raba(a, b) {
+    idan b == 0 {
+      jefa "Ba za a raba da sifili ba"
+    }
+    mayar a / b
+  }
+  var sakamako = ""
+  gwada {
+    raba(1, 0)
+  } kama k {
+    sakamako = k.iri() + ": " + k.saƙo()
+  }
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(shown(&interpreter, "sakamako"), "Jefa: Ba za a raba da sifili ba");
+}
+
+#[test]
+fn test_uncaught_throw_stops_the_program() {
+    let error = run("fara\n  jefa \"matsala\"\nƙare").err().expect("Expected an error");
+    assert!(matches!(error, Error::Thrown(..)));
+    assert_eq!(error.message(), "matsala");
+    assert_eq!(error.span().map(|s| s.line), Some(2));
+}
+
+#[test]
+fn test_rethrow_keeps_original_error() {
+    let source = r#"
+fara
+  var iri = ""
+  var layi = 0
+  gwada {
+    gwada {
+      var jeri = []
+      var x = jeri[0]
+    } kama ciki {
+      jefa ciki
+    }
+  } kama waje {
+    iri = waje.iri()
+    layi = waje.layi()
+  }
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(shown(&interpreter, "iri"), "Runtime");
+    assert_eq!(shown(&interpreter, "layi"), "8");
+}
+
+#[test]
+fn test_finally_always_runs() {
+    let source = r#"
+fara
+  var tarihi = []
+  gwada {
+    tarihi.kara("gwada")
+  } kama {
+    tarihi.kara("kama")
+  } a_ƙarshe {
+    tarihi.kara("a_ƙarshe")
+  }
+  gwada {
+    jefa "x"
+  } kama {
+    tarihi.kara("kama")
+  } a_karshe {
+    tarihi.kara("a_karshe")
+  }
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(shown(&interpreter, "tarihi"), "[gwada, a_ƙarshe, kama, a_karshe]");
+}
+
+#[test]
+fn test_finally_without_catch_lets_error_through() {
+    let source = "fara\n  var tarihi = []\n  gwada {\n    jefa \"x\"\n  } a_ƙarshe {\n    tarihi.kara(1)\n  }\nƙare";
+    let error = run(source).err().expect("Expected an error");
+    assert_eq!(error.message(), "x");
+}
+
+#[test]
+fn test_return_and_break_pass_through_finally() {
+    let source = r#"
+fara
+  var tarihi = []
+  aiki samu() {
+    gwada {
+      mayar 1
+    } a_ƙarshe {
+      tarihi.kara("a_ƙarshe")
+    }
+    mayar 2
+  }
+  var a = samu()
+  ga i cikin 1..10 {
+    gwada {
+      idan i == 2 {
+        katse
+      }
+    } a_ƙarshe {
+      tarihi.kara(i)
+    }
+  }
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    assert_eq!(shown(&interpreter, "a"), "1");
+    assert_eq!(shown(&interpreter, "tarihi"), "[a_ƙarshe, 1, 2]");
+}
+
+#[test]
+fn test_catch_restores_scopes_after_error_in_function() {
+    let source = r#"
+fara
+  aiki zurfi(n) {
+    idan n == 0 {
+      jefa "ƙasa"
+    }
+    mayar zurfi(n - 1)
+  }
+  gwada {
+    zurfi(5)
+  } kama {
+  }
+  var bayan = 1
+ƙare
+"#;
+    let interpreter = run(source).unwrap();
+    // `var` au niveau global doit à nouveau créer une globale
+    assert_eq!(shown(&interpreter, "bayan"), "1");
+}
+
+#[test]
+fn test_try_requires_catch_or_finally() {
+    let error = run("fara\n  gwada {\n    rubuta 1\n  }\nƙare").err().expect("Expected an error");
+    assert!(matches!(error, Error::ParseError(..)), "{}", error);
+}
@@ -7,13 +7,14 @@ let runtime;
 const KEYWORDS = new Set([
     'fara', 'ƙare', 'kare', 'rubuta', 'idan', 'amma', 'ammaina',
     'maimaita', 'ga', 'cikin', 'katse', 'ci_gaba', 'aiki', 'mayar',
+    'da', 'ko', 'ba', 'taki', 'gwada', 'kama', 'a_ƙarshe', 'a_karshe', 'jefa', 'tabbatar', 'gwaji',
 ]);
 
 const DECL_KEYWORDS = new Set(['var', 'naɗa', 'nada']);
 
 const BOOLEANS = new Set(['gaskiya', 'karya']);
 
-const BUILTINS = new Set(['karɓa']);
+const BUILTINS = new Set(['karɓa', 'karɓa_lamba', 'karɓa_jimla', 'karɓa_ee', 'karba_lamba', 'karba_jimla', 'karba_ee']);
 
 // Tokenize source into spans with CSS classes
 function highlightCode(source) {
@@ -319,7 +320,9 @@ function runCode() {
     } catch (e) {
         const errorMsg = typeof e === 'string' ? e : e.message || String(e);
         outputEl.textContent = errorMsg;
-        showError(errorMsg);
+        // The full diagnostic (source line + caret) goes to the output pane,
+        // the error bar only shows its headline
+        showError(errorMsg.split('\n')[0]);
     }
 }
 
@@ -362,6 +365,11 @@ async function main() {
     try {
         await init();
         runtime = new DabaraRuntime();
+        // A student's endless loop must not freeze the tab. A step budget rather
+        // than a timeout, since time spent answering karɓa would count against it
+        runtime.set_step_limit(10000000);
+        // karɓa asks the user through the browser's own dialog
+        runtime.set_input_handler((prompt) => window.prompt(prompt));
 
         // Show version
         try {